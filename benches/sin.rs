@@ -0,0 +1,79 @@
+use canada_sin::{SINRange, SINSet, SIN};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[allow(clippy::unnecessary_to_owned)]
+fn sins() -> Vec<SIN> {
+    let start = SIN::parse("046454286".to_string()).unwrap();
+    let end = SIN::parse("046464285".to_string()).unwrap();
+    SINRange::new(start, end).iter().collect()
+}
+
+fn parse(c: &mut Criterion) {
+    c.bench_function("parse", |b| {
+        b.iter(|| SIN::parse(black_box("046-454-286".to_string())))
+    });
+}
+
+fn is_valid(c: &mut Criterion) {
+    c.bench_function("is_valid", |b| {
+        b.iter(|| SIN::is_valid(black_box("046-454-286")))
+    });
+    c.bench_function("parse_is_ok", |b| {
+        b.iter(|| SIN::parse(black_box("046-454-286".to_string())).is_ok())
+    });
+}
+
+#[allow(clippy::unnecessary_to_owned)]
+fn format(c: &mut Criterion) {
+    let sin = SIN::parse("046454286".to_string()).unwrap();
+    c.bench_function("digits", |b| b.iter(|| black_box(sin).digits()));
+    c.bench_function("to_string", |b| b.iter(|| black_box(sin).to_string()));
+}
+
+/// How `SIN` was stored before it was packed into a `u32`, to compare against.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct UnpackedSIN([u8; 9]);
+
+fn compare(c: &mut Criterion) {
+    let sins = sins();
+    let unpacked: Vec<UnpackedSIN> = sins.iter().map(|sin| UnpackedSIN(sin.digits())).collect();
+    let mut group = c.benchmark_group("sort");
+    group.bench_function("packed", |b| {
+        b.iter(|| {
+            let mut sins = sins.clone();
+            sins.sort_unstable_by(|a, b| b.cmp(a));
+            sins
+        })
+    });
+    group.bench_function("unpacked", |b| {
+        b.iter(|| {
+            let mut sins = unpacked.clone();
+            sins.sort_unstable_by(|a, b| b.cmp(a));
+            sins
+        })
+    });
+    group.finish();
+    let mut group = c.benchmark_group("eq");
+    group.bench_function("packed", |b| {
+        b.iter(|| {
+            sins.iter()
+                .filter(|&&sin| sin == black_box(sins[0]))
+                .count()
+        })
+    });
+    group.bench_function("unpacked", |b| {
+        b.iter(|| {
+            unpacked
+                .iter()
+                .filter(|&&sin| sin == black_box(unpacked[0]))
+                .count()
+        })
+    });
+    group.finish();
+    c.bench_function("set_insert", |b| {
+        b.iter(|| sins.iter().copied().collect::<SINSet>())
+    });
+}
+
+criterion_group!(benches, parse, is_valid, format, compare);
+criterion_main!(benches);
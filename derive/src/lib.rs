@@ -0,0 +1,116 @@
+//! Derive macros for the `canada_sin` crate. Use these through the `derive` feature of
+//! `canada_sin`, instead of depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Member};
+
+/// Derives the usual SIN functionality for a struct with a single `SIN` field, such as
+/// `struct EmployeeSIN(SIN);`, so that each domain can have its own SIN type without any
+/// boilerplate. This generates:
+/// - `parse`, which works like `SIN::parse`, along with `FromStr`
+/// - `sin`, which returns the SIN, along with `From<SIN>` and `From<Self> for SIN`
+/// - `masked`, which hides all but the last 3 digits, like `***-***-286`
+/// - `Display`, which formats the SIN like `SIN` does
+/// - `Serialize` and `Deserialize`, if the `serde` feature of `canada_sin` is enabled
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{SINNewtype, SIN};
+///
+/// #[derive(SINNewtype)]
+/// struct EmployeeSIN(SIN);
+///
+/// let sin = EmployeeSIN::parse("046 454 286".to_string()).unwrap();
+/// assert_eq!(sin.to_string(), "046-454-286");
+/// assert_eq!(sin.masked(), "***-***-286");
+/// assert_eq!(serde_json::to_string(&sin).unwrap(), r#""046454286""#);
+/// assert!("046-454-287".parse::<EmployeeSIN>().is_err());
+/// ```
+#[proc_macro_derive(SINNewtype)]
+pub fn derive_sin_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match sin_newtype(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn sin_newtype(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "SINNewtype can't be derived for generic types",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &input,
+                "SINNewtype can only be derived for structs",
+            ))
+        }
+    };
+    let field = match fields {
+        Fields::Named(_) | Fields::Unnamed(_) if fields.len() == 1 => fields.iter().next().unwrap(),
+        _ => {
+            return Err(Error::new_spanned(
+                fields,
+                "SINNewtype can only be derived for structs with a single SIN field",
+            ))
+        }
+    };
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(0.into()),
+    };
+    Ok(quote! {
+        impl #name {
+            /// Parses a SIN from a string, like `SIN::parse`.
+            pub fn parse(
+                s: impl ::std::convert::AsRef<str>,
+            ) -> ::std::result::Result<Self, ::canada_sin::SINParseError> {
+                ::canada_sin::SIN::parse(s).map(Self::from)
+            }
+            /// Returns the SIN.
+            pub fn sin(&self) -> ::canada_sin::SIN {
+                self.#member
+            }
+            /// Returns the SIN with all but the last 3 digits hidden, like `***-***-286`.
+            pub fn masked(&self) -> ::std::string::String {
+                self.#member.masked()
+            }
+        }
+
+        impl ::std::convert::From<::canada_sin::SIN> for #name {
+            fn from(sin: ::canada_sin::SIN) -> Self {
+                Self { #member: sin }
+            }
+        }
+
+        impl ::std::convert::From<#name> for ::canada_sin::SIN {
+            fn from(newtype: #name) -> Self {
+                newtype.#member
+            }
+        }
+
+        impl ::std::str::FromStr for #name {
+            type Err = ::canada_sin::SINParseError;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                Self::parse(s.to_string())
+            }
+        }
+
+        impl ::std::fmt::Display for #name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(&self.#member, f)
+            }
+        }
+
+        ::canada_sin::__sin_newtype_serde!(#name);
+    })
+}
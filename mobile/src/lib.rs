@@ -0,0 +1,218 @@
+//! Kotlin and Swift bindings for `canada_sin`, so mobile apps can validate SINs on the device with
+//! the same logic as the server. The interface is defined in `canada_sin.udl`; the types here
+//! mirror the ones in `canada_sin` in a form that UniFFI can export.
+
+// The generated scaffolding has a doc comment followed by a blank line
+#![allow(clippy::empty_line_after_doc_comments)]
+
+use std::fmt;
+
+/// An error resulting from parsing a SIN. This mirrors [`canada_sin::SINParseError`], which can't
+/// be exported directly since it's non-exhaustive.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SINParseError {
+    TooLong,
+    TooShort,
+    InvalidChecksum,
+    InvalidDigit,
+    Placeholder,
+    WrongType,
+    InvalidCharacter,
+    MisplacedSeparator,
+    /// An error that was added to `canada_sin` after these bindings.
+    Other,
+}
+
+impl From<canada_sin::SINParseError> for SINParseError {
+    fn from(err: canada_sin::SINParseError) -> Self {
+        use canada_sin::SINParseError as E;
+        match err {
+            E::TooLong => Self::TooLong,
+            E::TooShort => Self::TooShort,
+            E::InvalidChecksum => Self::InvalidChecksum,
+            E::InvalidDigit => Self::InvalidDigit,
+            E::Placeholder => Self::Placeholder,
+            E::WrongType => Self::WrongType,
+            E::InvalidCharacter => Self::InvalidCharacter,
+            E::MisplacedSeparator => Self::MisplacedSeparator,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl fmt::Display for SINParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use canada_sin::SINParseError as E;
+        let err = match self {
+            Self::TooLong => E::TooLong,
+            Self::TooShort => E::TooShort,
+            Self::InvalidChecksum => E::InvalidChecksum,
+            Self::InvalidDigit => E::InvalidDigit,
+            Self::Placeholder => E::Placeholder,
+            Self::WrongType => E::WrongType,
+            Self::InvalidCharacter => E::InvalidCharacter,
+            Self::MisplacedSeparator => E::MisplacedSeparator,
+            Self::Other => return f.write_str("SIN is invalid"),
+        };
+        fmt::Display::fmt(&err, f)
+    }
+}
+
+impl std::error::Error for SINParseError {}
+
+/// A language that names can be rendered in. This mirrors [`canada_sin::Locale`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Locale {
+    English,
+    French,
+}
+
+impl From<Locale> for canada_sin::Locale {
+    fn from(locale: Locale) -> Self {
+        match locale {
+            Locale::English => Self::English,
+            Locale::French => Self::French,
+        }
+    }
+}
+
+/// A type of SIN. This mirrors [`canada_sin::SINType`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SINType {
+    CRAAssigned,
+    TemporaryResident,
+    BusinessNumber,
+    OverseasForces,
+    Alberta,
+    BritishColumbia,
+    Manitoba,
+    NewBrunswick,
+    NewfoundlandLabrador,
+    NorthwestTerritories,
+    NovaScotia,
+    Nunavut,
+    Ontario,
+    PrinceEdwardIsland,
+    Quebec,
+    Saskatchewan,
+    Yukon,
+    /// A type that was added to `canada_sin` after these bindings.
+    Other,
+}
+
+/// Pairs of mirrored types, used to convert in both directions.
+const TYPES: [(SINType, canada_sin::SINType); 17] = {
+    use canada_sin::SINType as T;
+    [
+        (SINType::CRAAssigned, T::CRAAssigned),
+        (SINType::TemporaryResident, T::TemporaryResident),
+        (SINType::BusinessNumber, T::BusinessNumber),
+        (SINType::OverseasForces, T::OverseasForces),
+        (SINType::Alberta, T::Alberta),
+        (SINType::BritishColumbia, T::BritishColumbia),
+        (SINType::Manitoba, T::Manitoba),
+        (SINType::NewBrunswick, T::NewBrunswick),
+        (SINType::NewfoundlandLabrador, T::NewfoundlandLabrador),
+        (SINType::NorthwestTerritories, T::NorthwestTerritories),
+        (SINType::NovaScotia, T::NovaScotia),
+        (SINType::Nunavut, T::Nunavut),
+        (SINType::Ontario, T::Ontario),
+        (SINType::PrinceEdwardIsland, T::PrinceEdwardIsland),
+        (SINType::Quebec, T::Quebec),
+        (SINType::Saskatchewan, T::Saskatchewan),
+        (SINType::Yukon, T::Yukon),
+    ]
+};
+
+impl From<canada_sin::SINType> for SINType {
+    fn from(ty: canada_sin::SINType) -> Self {
+        TYPES
+            .iter()
+            .find(|(_, inner)| *inner == ty)
+            .map_or(Self::Other, |(ty, _)| *ty)
+    }
+}
+
+/// Checks if a string is a valid SIN.
+pub fn is_valid_sin(input: &str) -> bool {
+    canada_sin::SIN::is_valid(input)
+}
+
+/// The name of a type in the given language.
+pub fn sin_type_name(ty: SINType, locale: Locale) -> String {
+    match TYPES.iter().find(|(mirror, _)| *mirror == ty) {
+        Some((_, inner)) => inner.name(locale.into()).to_string(),
+        None => match locale {
+            Locale::English => "Other".to_string(),
+            Locale::French => "Autre".to_string(),
+        },
+    }
+}
+
+/// A social insurance number.
+#[derive(Debug)]
+pub struct SIN {
+    sin: canada_sin::SIN,
+}
+
+impl SIN {
+    /// Parses a SIN.
+    pub fn new(input: &str) -> Result<Self, SINParseError> {
+        Ok(Self {
+            sin: canada_sin::SIN::parse(input)?,
+        })
+    }
+    /// The SIN as 9 digits.
+    pub fn digits(&self) -> String {
+        self.sin.digits_string()
+    }
+    /// The SIN with dashes.
+    pub fn dashed(&self) -> String {
+        self.sin.digits_dashed_string()
+    }
+    /// The SIN with all but the last 3 digits hidden.
+    pub fn masked(&self) -> String {
+        self.sin.masked()
+    }
+    /// All types the SIN could be.
+    pub fn types(&self) -> Vec<SINType> {
+        self.sin.types().into_iter().map(SINType::from).collect()
+    }
+    /// Was the SIN given to a temporary resident?
+    pub fn is_temporary(&self) -> bool {
+        self.sin.is_nine_series()
+    }
+    /// Is the SIN a placeholder value that is never issued?
+    pub fn is_placeholder(&self) -> bool {
+        self.sin.is_placeholder()
+    }
+    /// Explains how the SIN is classified.
+    pub fn explain(&self) -> String {
+        self.sin.explain()
+    }
+}
+
+uniffi::include_scaffolding!("canada_sin");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mirrored_types() {
+        assert_eq!(
+            SIN::new("734323843").unwrap().types(),
+            vec![
+                SINType::BritishColumbia,
+                SINType::Yukon,
+                SINType::BusinessNumber
+            ]
+        );
+        assert_eq!(
+            SIN::new("046454287").unwrap_err().to_string(),
+            "SIN has an invalid checksum"
+        );
+        assert_eq!(sin_type_name(SINType::Quebec, Locale::French), "Québec");
+        assert_eq!(canada_sin::SINType::all().count(), TYPES.len());
+    }
+}
@@ -0,0 +1,118 @@
+//! Native Node.js bindings for `canada_sin`, for servers where the overhead of WASM matters.
+//! Build with the napi-rs CLI (`napi build --release` in this directory).
+//!
+//! Functions that take a `Buffer` read it in place, without copying it into a JavaScript string
+//! first, and offsets returned by [`scan`] are byte offsets into the buffer. Invalid SINs throw an
+//! `Error` with a `code` like `"INVALID_CHECKSUM"`.
+//!
+//! ```js
+//! const { parse, isValid, validateLines, scan } = require("canada_sin_node");
+//!
+//! parse("046 454 286"); // "046454286"
+//! isValid(Buffer.from("046-454-287")); // false
+//! validateLines(fs.readFileSync("sins.txt")); // [true, false, ...]
+//! scan(Buffer.from("SIN: 046-454-286")); // [{ sin: "046454286", start: 5, end: 16, ... }]
+//! ```
+
+use canada_sin::{SINParseError, Scanner, SIN};
+use napi::bindgen_prelude::Buffer;
+use napi_derive::napi;
+use std::str;
+
+/// The code of a parse error, which becomes the `code` of the thrown `Error`. This is
+/// `SINParseError::code` in upper case, like Node's own error codes.
+pub struct ErrorCode(String);
+
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<SINParseError> for ErrorCode {
+    fn from(err: SINParseError) -> Self {
+        Self(err.code().to_ascii_uppercase())
+    }
+}
+
+fn to_napi_error(err: SINParseError) -> napi::Error<ErrorCode> {
+    napi::Error::new(err.into(), err.to_string())
+}
+
+/// Checks if some bytes are a valid SIN. Bytes that aren't UTF-8 are never valid.
+fn is_valid_bytes(bytes: &[u8]) -> bool {
+    str::from_utf8(bytes).is_ok_and(SIN::is_valid)
+}
+
+/// Parses a SIN, returning its 9 digits. Throws if the SIN isn't valid.
+#[napi]
+pub fn parse(input: String) -> napi::Result<String, ErrorCode> {
+    SIN::parse(input)
+        .map(SIN::digits_string)
+        .map_err(to_napi_error)
+}
+
+/// Checks if a buffer contains a valid SIN, without copying it.
+#[napi]
+pub fn is_valid(input: Buffer) -> bool {
+    is_valid_bytes(&input)
+}
+
+/// Checks every line of a buffer, such as the contents of a file, returning whether each line is a
+/// valid SIN. This is much faster than calling `isValid` for each line.
+#[napi]
+pub fn validate_lines(input: Buffer) -> Vec<bool> {
+    input
+        .split(|&byte| byte == b'\n')
+        .map(|line| is_valid_bytes(line.strip_suffix(b"\r").unwrap_or(line)))
+        .collect()
+}
+
+/// A SIN found by [`scan`].
+#[napi(object)]
+pub struct ScanMatch {
+    /// The 9 digits of the SIN.
+    pub sin: String,
+    /// The byte offset where the SIN starts.
+    pub start: u32,
+    /// The byte offset just after the end of the SIN.
+    pub end: u32,
+    /// How likely the match is to be a SIN, from 0 to 1.
+    pub confidence: f64,
+}
+
+/// Finds SINs in a buffer of UTF-8 text with the default scanner settings. Invalid UTF-8 is
+/// scanned up to the first invalid byte.
+#[napi]
+pub fn scan(text: Buffer) -> Vec<ScanMatch> {
+    let text = match str::from_utf8(&text) {
+        Ok(text) => text,
+        Err(err) => str::from_utf8(&text[..err.valid_up_to()]).unwrap(),
+    };
+    Scanner::new()
+        .scan(text)
+        .into_iter()
+        .map(|found| ScanMatch {
+            sin: found.sin.digits_string(),
+            start: found.start as u32,
+            end: found.end as u32,
+            confidence: f64::from(found.confidence),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes() {
+        assert!(is_valid_bytes(b"046-454-286"));
+        assert!(!is_valid_bytes(b"046-454-287"));
+        assert!(!is_valid_bytes(b"\xff046454286"));
+        assert_eq!(
+            ErrorCode::from(SINParseError::InvalidChecksum).as_ref(),
+            "INVALID_CHECKSUM"
+        );
+    }
+}
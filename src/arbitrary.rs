@@ -0,0 +1,55 @@
+//! Support for property-based testing with `quickcheck`.
+
+use crate::{SIN, VALID_SINS};
+use quickcheck::{Arbitrary, Gen};
+
+impl Arbitrary for SIN {
+    /// Generates a SIN with a valid checksum.
+    fn arbitrary(g: &mut Gen) -> Self {
+        SIN::from_body_number(u32::arbitrary(g) % VALID_SINS)
+    }
+    /// Shrinks toward `000-000-000`, by setting each of the first 8 digits to 0 or lowering it
+    /// by 1. The check digit is recomputed for each candidate.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// use quickcheck::Arbitrary;
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// assert!(sin.shrink().all(|smaller| smaller < sin));
+    /// ```
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let body = self.body_number();
+        let candidates = (0..8).flat_map(move |position| {
+            let place = 10u32.pow(7 - position);
+            let digit = (body / place) % 10;
+            let zeroed = body - digit * place;
+            match digit {
+                0 => vec![],
+                1 => vec![zeroed],
+                _ => vec![zeroed, body - place],
+            }
+        });
+        Box::new(candidates.map(SIN::from_body_number))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn shrinks_to_zero() {
+        let zero = SIN::parse("000000000".to_string()).unwrap();
+        assert_eq!(zero.shrink().count(), 0);
+        let sin = SIN::parse("000000018".to_string()).unwrap();
+        assert_eq!(sin.shrink().collect::<Vec<_>>(), vec![zero]);
+    }
+
+    quickcheck::quickcheck! {
+        fn arbitrary_sins_are_valid(sin: SIN) -> bool {
+            SIN::parse(sin.digits_string()) == Ok(sin)
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Payloads for printing SINs as barcodes, such as on intake forms. Anyone with a scanner can read
+//! a barcode, so barcodes are masked unless the caller explicitly acknowledges that the full SIN
+//! will be exposed.
+
+use crate::SIN;
+
+/// An acknowledgement that a barcode will contain a full SIN, which anyone with a scanner can
+/// read. This exists so that code creating unmasked barcodes stands out in review.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ExposesFullSIN;
+
+/// What a barcode contains.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BarcodeContent {
+    /// Only the last 3 digits, like `XXX-XXX-286`. `X` is used instead of `*`, since `*` marks the
+    /// start and end of Code 39 barcodes.
+    Masked,
+    /// The full SIN, like `046454286`.
+    Full(ExposesFullSIN),
+}
+
+impl SIN {
+    /// The text of the barcode, without start and stop characters.
+    fn barcode_text(&self, content: BarcodeContent) -> String {
+        match content {
+            BarcodeContent::Masked => {
+                let [a, b, c] = self.last_three();
+                format!("XXX-XXX-{}{}{}", a, b, c)
+            }
+            BarcodeContent::Full(ExposesFullSIN) => self.digits_string(),
+        }
+    }
+    /// The bytes to encode in a Code 39 barcode, including the `*` start and stop characters.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{BarcodeContent, ExposesFullSIN, SIN};
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// assert_eq!(sin.code39_payload(BarcodeContent::Masked), b"*XXX-XXX-286*");
+    /// assert_eq!(
+    ///     sin.code39_payload(BarcodeContent::Full(ExposesFullSIN)),
+    ///     b"*046454286*",
+    /// );
+    /// ```
+    pub fn code39_payload(&self, content: BarcodeContent) -> Vec<u8> {
+        format!("*{}*", self.barcode_text(content)).into_bytes()
+    }
+    /// The bytes to encode in a QR code. Every character is in the QR alphanumeric character
+    /// set, and full SINs are only digits, so QR encoders can use the compact numeric or
+    /// alphanumeric modes.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{BarcodeContent, ExposesFullSIN, SIN};
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// assert_eq!(sin.qr_payload(BarcodeContent::Masked), b"XXX-XXX-286");
+    /// assert_eq!(sin.qr_payload(BarcodeContent::Full(ExposesFullSIN)), b"046454286");
+    /// ```
+    pub fn qr_payload(&self, content: BarcodeContent) -> Vec<u8> {
+        self.barcode_text(content).into_bytes()
+    }
+}
@@ -0,0 +1,413 @@
+//! A command line tool for working with SINs.
+
+use canada_sin::{SINBuilder, SINProblem, SIN};
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use serde_json::{json, Value};
+use std::{
+    convert::TryInto,
+    io::{self, BufRead, Write},
+    process::ExitCode,
+};
+
+fn command() -> Command {
+    Command::new("canada-sin")
+        .about("Tools for Canadian social insurance numbers")
+        .version(env!("CARGO_PKG_VERSION"))
+        .subcommand_required(true)
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .global(true)
+                .value_parser(["text", "json"])
+                .default_value("text")
+                .help("The output format"),
+        )
+        .subcommand(
+            Command::new("explain")
+                .about("Explains how a SIN is classified")
+                .arg(Arg::new("sin").required(true).help("The SIN to explain")),
+        )
+        .subcommand(
+            Command::new("complete")
+                .about("Computes the check digit for the first 8 digits of a SIN")
+                .arg(
+                    Arg::new("digits")
+                        .required(true)
+                        .help("The first 8 digits, optionally with spaces or dashes"),
+                ),
+        )
+        .subcommand(
+            Command::new("validate")
+                .about("Validates SINs, exiting with an error if any are invalid")
+                .arg(
+                    Arg::new("sins")
+                        .num_args(0..)
+                        .required_unless_present("stdin")
+                        .help("The SINs to validate"),
+                )
+                .arg(
+                    Arg::new("stdin")
+                        .long("stdin")
+                        .action(ArgAction::SetTrue)
+                        .help("Also reads SINs from stdin, one per line"),
+                )
+                .arg(
+                    Arg::new("summary")
+                        .long("summary")
+                        .action(ArgAction::SetTrue)
+                        .help("Prints the number of valid and invalid SINs to stderr"),
+                ),
+        )
+}
+
+/// How results are printed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Format {
+    Text,
+    /// One JSON object per result, on its own line.
+    Json,
+}
+
+impl Format {
+    fn from_matches(matches: &ArgMatches) -> Self {
+        match matches.get_one::<String>("format").map(String::as_str) {
+            Some("json") => Self::Json,
+            _ => Self::Text,
+        }
+    }
+}
+
+/// The JSON result for checking `input`. Invalid input lists every problem with it, instead of
+/// just the first one.
+fn validation_json(input: &str) -> Value {
+    match SIN::parse(input) {
+        Ok(sin) => json!({
+            "value": input,
+            "valid": true,
+            "types": sin.types().iter().map(|ty| format!("{:?}", ty)).collect::<Vec<_>>(),
+            "errors": [],
+        }),
+        Err(err) => {
+            let mut errors: Vec<String> = SIN::check(input)
+                .into_iter()
+                .filter(|problem| *problem != SINProblem::Placeholder)
+                .map(|problem| problem.to_string())
+                .collect();
+            if errors.is_empty() {
+                errors.push(err.to_string());
+            }
+            json!({
+                "value": input,
+                "valid": false,
+                "types": [],
+                "errors": errors,
+            })
+        }
+    }
+}
+
+/// The JSON printed by `canada-sin explain`, which adds details to [`validation_json`] for valid
+/// SINs.
+fn explain_json(input: &str) -> Value {
+    let mut result = validation_json(input);
+    if let Ok(sin) = SIN::parse(input) {
+        let info = sin.info();
+        let details = json!({
+            "explanation": sin.explain(),
+            "digits": info.digits,
+            "dashed": info.dashed,
+            "spaced": sin.digits_spaced_string(),
+            "masked": info.masked,
+            "could_be_person": info.could_be_human,
+            "could_be_business": info.could_be_business,
+            "temporary_resident": info.is_nine_series,
+            "placeholder": sin.is_placeholder(),
+            "known_example": sin.is_known_example(),
+            "plausible": sin.plausibility().is_plausible(),
+        });
+        if let (Value::Object(result), Value::Object(details)) = (&mut result, details) {
+            result.extend(details);
+        }
+    }
+    result
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+/// The report printed by `canada-sin explain`.
+fn explain(sin: SIN) -> String {
+    let info = sin.info();
+    let types: Vec<String> = info.types.iter().map(|ty| ty.to_string()).collect();
+    let rows = [
+        ("Types", types.join(", ")),
+        ("Digits", info.digits),
+        ("Dashed", info.dashed),
+        ("Spaced", sin.digits_spaced_string()),
+        ("Masked", info.masked),
+        ("Could be a person", yes_no(info.could_be_human).to_string()),
+        (
+            "Could be a business",
+            yes_no(info.could_be_business).to_string(),
+        ),
+        (
+            "Temporary resident",
+            yes_no(info.is_nine_series).to_string(),
+        ),
+        ("Placeholder", yes_no(sin.is_placeholder()).to_string()),
+        ("Known example", yes_no(sin.is_known_example()).to_string()),
+        (
+            "Plausible",
+            yes_no(sin.plausibility().is_plausible()).to_string(),
+        ),
+    ];
+    let mut report = sin.explain();
+    report.push('\n');
+    for (label, value) in rows {
+        report.push_str(&format!("\n{:<20} {}", format!("{}:", label), value));
+    }
+    report
+}
+
+fn run_explain(matches: &ArgMatches) -> ExitCode {
+    let input = matches.get_one::<String>("sin").unwrap();
+    if Format::from_matches(matches) == Format::Json {
+        let result = explain_json(input);
+        println!("{}", result);
+        return if result["valid"] == true {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        };
+    }
+    match SIN::parse(input) {
+        Ok(sin) => {
+            println!("{}", explain(sin));
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Completes the first 8 digits of a SIN, which may be separated by spaces or dashes.
+fn complete(input: &str) -> Result<SIN, &'static str> {
+    let mut digits = Vec::new();
+    for c in input.chars() {
+        match c {
+            '0'..='9' => digits.push(c as u8 - b'0'),
+            ' ' | '-' => {}
+            _ => return Err("input contains a character that isn't a digit"),
+        }
+    }
+    let body: [u8; 8] = digits
+        .try_into()
+        .map_err(|_| "input must have exactly 8 digits")?;
+    Ok(SINBuilder::new(body).build().expect("digits are at most 9"))
+}
+
+fn run_complete(matches: &ArgMatches) -> ExitCode {
+    let input = matches.get_one::<String>("digits").unwrap();
+    let result = complete(input);
+    if Format::from_matches(matches) == Format::Json {
+        println!(
+            "{}",
+            match result {
+                Ok(sin) => json!({
+                    "value": input,
+                    "valid": true,
+                    "check_digit": sin.digits()[8],
+                    "sin": sin.digits_string(),
+                    "errors": [],
+                }),
+                Err(err) => json!({ "value": input, "valid": false, "errors": [err] }),
+            }
+        );
+        return if result.is_ok() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        };
+    }
+    match result {
+        Ok(sin) => {
+            println!("Check digit: {}", sin.digits()[8]);
+            println!("SIN:         {}", sin);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Counts of the SINs checked by `canada-sin validate`.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Summary {
+    valid: usize,
+    invalid: usize,
+}
+
+/// Validates each candidate, writing a tab-separated line (or a JSON object) with the result for
+/// each one. Blank candidates are skipped.
+fn validate<W: Write>(
+    candidates: impl Iterator<Item = String>,
+    format: Format,
+    out: &mut W,
+    summary: &mut Summary,
+) -> io::Result<()> {
+    for candidate in candidates {
+        let candidate = candidate.trim();
+        if candidate.is_empty() {
+            continue;
+        }
+        if format == Format::Json {
+            let result = validation_json(candidate);
+            if result["valid"] == true {
+                summary.valid += 1;
+            } else {
+                summary.invalid += 1;
+            }
+            writeln!(out, "{}", result)?;
+            continue;
+        }
+        match SIN::parse(candidate) {
+            Ok(_) => {
+                summary.valid += 1;
+                writeln!(out, "{}\tvalid", candidate)?;
+            }
+            Err(err) => {
+                summary.invalid += 1;
+                writeln!(out, "{}\tinvalid\t{}", candidate, err)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn run_validate(matches: &ArgMatches) -> ExitCode {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut summary = Summary::default();
+    let format = Format::from_matches(matches);
+    let args = matches.get_many::<String>("sins").into_iter().flatten();
+    let mut result = validate(args.cloned(), format, &mut out, &mut summary);
+    if result.is_ok() && matches.get_flag("stdin") {
+        let stdin = io::stdin();
+        let mut read_error = None;
+        let candidates = stdin
+            .lock()
+            .lines()
+            .map_while(|line| line.map_err(|err| read_error = Some(err)).ok());
+        result = validate(candidates, format, &mut out, &mut summary);
+        if let Some(err) = read_error {
+            result = Err(err);
+        }
+    }
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        return ExitCode::FAILURE;
+    }
+    if matches.get_flag("summary") {
+        match format {
+            Format::Text => eprintln!("{} valid, {} invalid", summary.valid, summary.invalid),
+            Format::Json => eprintln!(
+                "{}",
+                json!({ "valid": summary.valid, "invalid": summary.invalid })
+            ),
+        }
+    }
+    if summary.invalid > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn main() -> ExitCode {
+    match command().get_matches().subcommand() {
+        Some(("explain", matches)) => run_explain(matches),
+        Some(("complete", matches)) => run_complete(matches),
+        Some(("validate", matches)) => run_validate(matches),
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn explain_report() {
+        let report = explain(SIN::parse("734323843".to_string()).unwrap());
+        assert!(report.starts_with("Starts with 7: "));
+        assert!(
+            report.contains("\nTypes:               British Columbia, Yukon, Business number\n")
+        );
+        assert!(report.contains("\nMasked:              ***-***-843\n"));
+        command().debug_assert();
+    }
+
+    #[test]
+    fn complete_digits() {
+        assert_eq!(complete("046-454-28").unwrap().to_string(), "046-454-286");
+        assert!(complete("0464542").is_err());
+        assert!(complete("046454286").is_err());
+        assert!(complete("04645428a").is_err());
+    }
+
+    #[test]
+    fn validate_lines() {
+        let input = "046 454 286\n\n046454287\r\n";
+        let mut out = Vec::new();
+        let mut summary = Summary::default();
+        let lines = input.lines().map(String::from);
+        validate(lines, Format::Text, &mut out, &mut summary).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "046 454 286\tvalid\n046454287\tinvalid\tSIN has an invalid checksum\n"
+        );
+        assert_eq!(
+            summary,
+            Summary {
+                valid: 1,
+                invalid: 1
+            }
+        );
+    }
+
+    #[test]
+    fn json_output() {
+        let result = validation_json("046-45A-28");
+        assert_eq!(result["valid"], false);
+        assert_eq!(
+            result["errors"],
+            json!([
+                "SIN contains an invalid character 'A' at position 6",
+                "SIN is less than 9 digits"
+            ])
+        );
+        let result = explain_json("734323843");
+        assert_eq!(
+            result["types"],
+            json!(["BritishColumbia", "Yukon", "BusinessNumber"])
+        );
+        assert_eq!(result["masked"], "***-***-843");
+        let mut out = Vec::new();
+        let lines = std::iter::once("046454286".to_string());
+        validate(lines, Format::Json, &mut out, &mut Summary::default()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"errors\":[],\"types\":[\"CRAAssigned\"],\"valid\":true,\"value\":\"046454286\"}\n"
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! Constructing valid SINs from chosen digits, for generating test data.
+
+use crate::{luhn, SINParseError, SINType, SIN};
+
+/// Builds a valid SIN from its first 8 digits by computing the check digit. The first digit can be
+/// replaced to get a SIN of a specific type.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{SINBuilder, SINType};
+/// let sin = SINBuilder::new([0, 4, 6, 4, 5, 4, 2, 8]).build().unwrap();
+/// assert_eq!(sin.digits_string(), "046454286");
+///
+/// let sin = SINBuilder::new([0, 4, 6, 4, 5, 4, 2, 8])
+///     .with_type(SINType::TemporaryResident)
+///     .build()
+///     .unwrap();
+/// assert_eq!(sin.digits_string(), "946454287");
+/// assert_eq!(sin.types(), vec![SINType::TemporaryResident]);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SINBuilder {
+    body: [u8; 8],
+}
+
+impl SINBuilder {
+    /// Creates a builder from the first 8 digits of a SIN.
+    pub fn new(body: [u8; 8]) -> Self {
+        Self { body }
+    }
+    /// Replaces the first digit.
+    pub fn with_first_digit(mut self, digit: u8) -> Self {
+        self.body[0] = digit;
+        self
+    }
+    /// Replaces the first digit with the smallest first digit that a SIN of type `ty` can have.
+    pub fn with_type(self, ty: SINType) -> Self {
+        let digit = ty.possible_first_digits()[0];
+        self.with_first_digit(digit)
+    }
+    /// Computes the check digit and creates the SIN. This fails if any of the digits are greater
+    /// than 9.
+    pub fn build(self) -> Result<SIN, SINParseError> {
+        if self.body.iter().any(|&digit| digit > 9) {
+            return Err(SINParseError::InvalidDigit);
+        }
+        let mut inner_digits = [0; 9];
+        inner_digits[..8].copy_from_slice(&self.body);
+        inner_digits[8] = luhn::check_digit(&self.body);
+        Ok(SIN::from_valid_digits(inner_digits))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_valid_sins() {
+        for first_digit in 0..=9 {
+            let sin = SINBuilder::new([1, 2, 3, 4, 5, 6, 7, 8])
+                .with_first_digit(first_digit)
+                .build()
+                .unwrap();
+            assert_eq!(sin.digits()[0], first_digit);
+            assert!(SIN::parse(sin.digits_string()).is_ok());
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_digits() {
+        assert_eq!(
+            SINBuilder::new([0; 8]).with_first_digit(10).build(),
+            Err(SINParseError::InvalidDigit)
+        );
+    }
+}
@@ -0,0 +1,410 @@
+//! Business numbers and their program accounts.
+
+use crate::{cra::check_length, luhn, AccountNumberParseError, SIN};
+use std::{convert::TryInto, fmt, str::FromStr};
+
+/// A business number (BN), the 9-digit number the CRA assigns to businesses. Business numbers
+/// share a namespace with SINs, and use the same Luhn checksum.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::BusinessNumber;
+/// let bn = BusinessNumber::parse("123 456 782".to_string()).unwrap();
+/// assert_eq!(bn.to_string(), "123456782");
+/// assert!(BusinessNumber::parse("123456789".to_string()).is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct BusinessNumber {
+    digits: [u8; 9],
+}
+
+/// A CRA program that a business can have an account for.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProgramIdentifier {
+    /// `RC`: corporate income tax.
+    CorporateIncomeTax,
+    /// `RM`: import/export, used by the CBSA.
+    ImportExport,
+    /// `RP`: payroll deductions.
+    Payroll,
+    /// `RR`: registered charities.
+    RegisteredCharity,
+    /// `RT`: GST/HST.
+    GSTHST,
+    /// `RZ`: information returns.
+    InformationReturns,
+}
+
+impl ProgramIdentifier {
+    /// The two letters of the program identifier.
+    pub fn code(self) -> &'static str {
+        use ProgramIdentifier::*;
+        match self {
+            CorporateIncomeTax => "RC",
+            ImportExport => "RM",
+            Payroll => "RP",
+            RegisteredCharity => "RR",
+            GSTHST => "RT",
+            InformationReturns => "RZ",
+        }
+    }
+    /// Finds the program with the given identifier.
+    pub fn from_code(code: &str) -> Option<Self> {
+        use ProgramIdentifier::*;
+        [
+            CorporateIncomeTax,
+            ImportExport,
+            Payroll,
+            RegisteredCharity,
+            GSTHST,
+            InformationReturns,
+        ]
+        .iter()
+        .copied()
+        .find(|program| program.code() == code)
+    }
+}
+
+/// A program account number, made of a business number, a program identifier, and a 4-digit
+/// reference number, like `123456782RP0001`. These are sometimes called BN15s.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{ProgramAccount, ProgramIdentifier};
+/// let account = ProgramAccount::parse("123456782RP0001".to_string()).unwrap();
+/// assert_eq!(account.program(), ProgramIdentifier::Payroll);
+/// assert!(account.is_payroll());
+/// assert_eq!(account.reference(), 1);
+/// assert_eq!(account.business_number().to_string(), "123456782");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ProgramAccount {
+    business_number: BusinessNumber,
+    program: ProgramIdentifier,
+    reference: ReferenceNumber,
+}
+
+/// The 4-digit reference number of a program account, from `0001` to `9999`. Reference numbers
+/// tell apart multiple accounts for the same program, and `0000` is never used.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::ReferenceNumber;
+/// let reference = ReferenceNumber::new(1).unwrap();
+/// assert_eq!(reference.get(), 1);
+/// assert_eq!(reference.to_string(), "0001");
+/// assert!(ReferenceNumber::new(0).is_none());
+/// assert!(ReferenceNumber::new(10000).is_none());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct ReferenceNumber(u16);
+
+impl ReferenceNumber {
+    /// Creates a reference number, which must be from 1 to 9999.
+    pub fn new(reference: u16) -> Option<Self> {
+        if (1..=9999).contains(&reference) {
+            Some(Self(reference))
+        } else {
+            None
+        }
+    }
+    /// The reference number as an integer.
+    pub fn get(self) -> u16 {
+        self.0
+    }
+}
+
+impl PartialEq<u16> for ReferenceNumber {
+    fn eq(&self, other: &u16) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for ReferenceNumber {
+    /// Formats the reference number as 4 digits, like `0001`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl BusinessNumber {
+    /// Parses a business number. Characters that aren't digits are ignored.
+    pub fn parse(s: String) -> Result<Self, AccountNumberParseError> {
+        Self::from_digit_vec(SIN::extract_digits(&s))
+    }
+    fn from_digit_vec(digits: Vec<u8>) -> Result<Self, AccountNumberParseError> {
+        check_length(&digits, 9)?;
+        if !luhn::is_valid(&digits) {
+            return Err(AccountNumberParseError::InvalidChecksum);
+        }
+        Ok(Self {
+            digits: digits.try_into().unwrap(),
+        })
+    }
+    /// Parses a business number from any of the ways CRA correspondence and invoices show it:
+    /// just the business number like `123456782`, or a program account number like
+    /// `123456782 RT 0001` or `123456782RT0001`. For program account numbers, the business number
+    /// is returned and the rest is checked and then ignored.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::BusinessNumber;
+    /// let bn = BusinessNumber::parse("123456782".to_string()).unwrap();
+    /// assert_eq!(BusinessNumber::parse_any("123 456 782".to_string()), Ok(bn));
+    /// assert_eq!(BusinessNumber::parse_any("123456782 RT 0001".to_string()), Ok(bn));
+    /// assert_eq!(BusinessNumber::parse_any("123456782RT0001".to_string()), Ok(bn));
+    /// assert!(BusinessNumber::parse_any("123456782RT0000".to_string()).is_err());
+    /// ```
+    pub fn parse_any(s: String) -> Result<Self, AccountNumberParseError> {
+        if s.chars().any(|c| c.is_ascii_alphabetic()) {
+            ProgramAccount::parse(s).map(ProgramAccount::business_number)
+        } else {
+            Self::parse(s)
+        }
+    }
+    /// Returns the 9 digits of the business number.
+    pub fn digits(self) -> [u8; 9] {
+        self.digits
+    }
+    /// Creates the program account for `program` with the reference number `reference`, which
+    /// must be from 1 to 9999.
+    pub fn program_account(
+        self,
+        program: ProgramIdentifier,
+        reference: u16,
+    ) -> Result<ProgramAccount, AccountNumberParseError> {
+        let reference =
+            ReferenceNumber::new(reference).ok_or(AccountNumberParseError::InvalidReference)?;
+        Ok(ProgramAccount {
+            business_number: self,
+            program,
+            reference,
+        })
+    }
+    /// Creates the payroll deductions (`RP`) account with the reference number `reference`, which
+    /// must be from 1 to 9999.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::BusinessNumber;
+    /// let bn = BusinessNumber::parse("123456782".to_string()).unwrap();
+    /// assert_eq!(bn.payroll_account(1).unwrap().to_string(), "123456782RP0001");
+    /// assert!(bn.payroll_account(0).is_err());
+    /// assert!(bn.payroll_account(10000).is_err());
+    /// ```
+    pub fn payroll_account(
+        self,
+        reference: u16,
+    ) -> Result<ProgramAccount, AccountNumberParseError> {
+        self.program_account(ProgramIdentifier::Payroll, reference)
+    }
+    /// The GST/HST (`RT`) account with reference number `0001`, which is the GST/HST registration
+    /// number printed on most invoices.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::BusinessNumber;
+    /// let bn = BusinessNumber::parse("123456782".to_string()).unwrap();
+    /// assert_eq!(bn.default_gst_hst().to_string(), "123456782RT0001");
+    /// ```
+    pub fn default_gst_hst(self) -> ProgramAccount {
+        ProgramAccount {
+            business_number: self,
+            program: ProgramIdentifier::GSTHST,
+            reference: ReferenceNumber(1),
+        }
+    }
+    /// Creates the import/export (`RM`) account with the reference number `reference`, which must
+    /// be from 1 to 9999. These accounts are used by the Canada Border Services Agency.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::BusinessNumber;
+    /// let bn = BusinessNumber::parse("123456782".to_string()).unwrap();
+    /// assert_eq!(bn.import_export_account(1).unwrap().to_string(), "123456782RM0001");
+    /// assert!(bn.import_export_account(0).is_err());
+    /// assert!(bn.import_export_account(10000).is_err());
+    /// ```
+    pub fn import_export_account(
+        self,
+        reference: u16,
+    ) -> Result<ProgramAccount, AccountNumberParseError> {
+        self.program_account(ProgramIdentifier::ImportExport, reference)
+    }
+}
+
+impl FromStr for BusinessNumber {
+    type Err = AccountNumberParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.to_string())
+    }
+}
+
+impl fmt::Display for BusinessNumber {
+    /// Formats the business number as 9 digits.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for digit in &self.digits {
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+impl ProgramAccount {
+    /// Parses a program account number. Spaces and dashes are ignored, and the program identifier
+    /// can be in either case. A program identifier that isn't known gives
+    /// [`AccountNumberParseError::InvalidProgram`], and a reference number that isn't 4 digits
+    /// from `0001` to `9999` gives [`AccountNumberParseError::InvalidReference`].
+    pub fn parse(s: String) -> Result<Self, AccountNumberParseError> {
+        let s: String = s
+            .chars()
+            .filter(|&c| c != ' ' && c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (digits, rest) = s.split_at(split);
+        let business_number = BusinessNumber::from_digit_vec(SIN::extract_digits(digits))?;
+        let split = rest
+            .find(|c: char| !c.is_ascii_uppercase())
+            .unwrap_or(rest.len());
+        let (program, reference) = rest.split_at(split);
+        let program =
+            ProgramIdentifier::from_code(program).ok_or(AccountNumberParseError::InvalidProgram)?;
+        if !reference.chars().all(|c| c.is_ascii_digit()) {
+            return Err(AccountNumberParseError::InvalidCharacter);
+        }
+        let reference = SIN::extract_digits(reference);
+        if reference.len() != 4 {
+            return Err(AccountNumberParseError::InvalidReference);
+        }
+        let reference = reference
+            .iter()
+            .fold(0, |acc, &digit| acc * 10 + u16::from(digit));
+        business_number.program_account(program, reference)
+    }
+    /// The business number the account belongs to.
+    pub fn business_number(self) -> BusinessNumber {
+        self.business_number
+    }
+    /// The program the account is for.
+    pub fn program(self) -> ProgramIdentifier {
+        self.program
+    }
+    /// The reference number, which tells apart multiple accounts for the same program.
+    pub fn reference(self) -> ReferenceNumber {
+        self.reference
+    }
+    /// Returns the account number with spaces around the program identifier, like
+    /// `123456782 RT 0001`, which is how CRA correspondence often shows it.
+    /// [`Display`](fmt::Display) gives the form without spaces, like `123456782RT0001`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::ProgramAccount;
+    /// let account = ProgramAccount::parse("123456782RT0001".to_string()).unwrap();
+    /// assert_eq!(account.spaced_string(), "123456782 RT 0001");
+    /// assert_eq!(account.to_string(), "123456782RT0001");
+    /// assert_eq!(account.business_number().to_string(), "123456782");
+    /// ```
+    pub fn spaced_string(self) -> String {
+        format!(
+            "{} {} {}",
+            self.business_number,
+            self.program.code(),
+            self.reference
+        )
+    }
+    /// Is this a payroll deductions (`RP`) account?
+    pub fn is_payroll(self) -> bool {
+        self.program == ProgramIdentifier::Payroll
+    }
+    /// Is this an import/export (`RM`) account?
+    pub fn is_import_export(self) -> bool {
+        self.program == ProgramIdentifier::ImportExport
+    }
+}
+
+impl FromStr for ProgramAccount {
+    type Err = AccountNumberParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.to_string())
+    }
+}
+
+impl fmt::Display for ProgramAccount {
+    /// Formats the account number like `123456782RP0001`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            self.business_number,
+            self.program.code(),
+            self.reference
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_program_account() {
+        let account: ProgramAccount = "123456782 rp 0002".parse().unwrap();
+        assert!(account.is_payroll());
+        assert_eq!(account.reference(), 2);
+        let account: ProgramAccount = "123456782RT0001".parse().unwrap();
+        assert!(!account.is_payroll());
+        assert_eq!(
+            ProgramAccount::parse("123456789RP0001".to_string()),
+            Err(AccountNumberParseError::InvalidChecksum)
+        );
+        assert_eq!(
+            ProgramAccount::parse("123456782XX0001".to_string()),
+            Err(AccountNumberParseError::InvalidProgram)
+        );
+        assert_eq!(
+            ProgramAccount::parse("123456782RP001".to_string()),
+            Err(AccountNumberParseError::InvalidReference)
+        );
+        assert_eq!(
+            ProgramAccount::parse("123456782RP00é1".to_string()),
+            Err(AccountNumberParseError::InvalidCharacter)
+        );
+        assert_eq!(
+            ProgramAccount::parse("123456782".to_string()),
+            Err(AccountNumberParseError::InvalidProgram)
+        );
+    }
+
+    #[test]
+    fn import_export_accounts() {
+        let account: ProgramAccount = "123456782RM0001".parse().unwrap();
+        assert!(account.is_import_export());
+        assert_eq!(
+            ProgramAccount::parse("123456782RM0000".to_string()),
+            Err(AccountNumberParseError::InvalidReference)
+        );
+    }
+
+    #[test]
+    fn reference_numbers() {
+        assert_eq!(
+            ProgramAccount::parse("123456782RT0000".to_string()),
+            Err(AccountNumberParseError::InvalidReference)
+        );
+        assert_eq!(
+            ProgramAccount::parse("123456782RT00001".to_string()),
+            Err(AccountNumberParseError::InvalidReference)
+        );
+        assert_eq!(
+            ProgramAccount::parse("12345678RT0001".to_string()),
+            Err(AccountNumberParseError::TooShort)
+        );
+        let account: ProgramAccount = "123456782RT9999".parse().unwrap();
+        assert_eq!(account.reference().get(), 9999);
+    }
+}
@@ -0,0 +1,129 @@
+//! Reporting everything that's wrong with a SIN, instead of just the first problem.
+
+use crate::{luhn, parse_options::DEFAULT_SEPARATORS, SINParseError, PLACEHOLDERS, SIN};
+use std::fmt;
+
+/// A problem with a SIN, found by [`SIN::check`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SINProblem {
+    /// The SIN is less than 9 digits.
+    TooShort,
+    /// The SIN is longer than 9 digits.
+    TooLong,
+    /// The SIN has an invalid Luhn checksum. This is only checked if there are exactly 9 digits.
+    InvalidChecksum,
+    /// The SIN is a placeholder value that is never issued, such as `000-000-000`.
+    Placeholder,
+    /// The SIN contains a character that isn't a digit or a separator. `position` is the index of
+    /// the character, counted in `char`s.
+    InvalidCharacter { position: usize, character: char },
+}
+
+impl SINProblem {
+    /// The [`SINParseError`] that corresponds to this problem.
+    pub fn error(self) -> SINParseError {
+        match self {
+            Self::TooShort => SINParseError::TooShort,
+            Self::TooLong => SINParseError::TooLong,
+            Self::InvalidChecksum => SINParseError::InvalidChecksum,
+            Self::Placeholder => SINParseError::Placeholder,
+            Self::InvalidCharacter { .. } => SINParseError::InvalidCharacter,
+        }
+    }
+}
+
+impl fmt::Display for SINProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCharacter {
+                position,
+                character,
+            } => write!(
+                f,
+                "SIN contains an invalid character {:?} at position {}",
+                character, position
+            ),
+            _ => write!(f, "{}", self.error()),
+        }
+    }
+}
+
+impl SIN {
+    /// Finds everything that's wrong with a SIN, for data quality reports. Unlike
+    /// [`SIN::parse`], which stops at the first problem, this reports all of them. The same
+    /// separators as [`ParseOptions::new`](crate::ParseOptions::new) are allowed, along with
+    /// whitespace at the start and end. An empty list means the SIN is valid and not a
+    /// placeholder.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{SINProblem, SIN};
+    /// assert_eq!(SIN::check("046-454-286".to_string()), vec![]);
+    /// assert_eq!(
+    ///     SIN::check("046-45A-28".to_string()),
+    ///     vec![
+    ///         SINProblem::InvalidCharacter { position: 6, character: 'A' },
+    ///         SINProblem::TooShort,
+    ///     ],
+    /// );
+    /// ```
+    pub fn check(s: impl AsRef<str>) -> Vec<SINProblem> {
+        let s = s.as_ref();
+        let mut problems = Vec::new();
+        let mut digits = Vec::with_capacity(9);
+        let leading = s.chars().take_while(|c| c.is_whitespace()).count();
+        for (position, khar) in s.trim().chars().enumerate() {
+            if let Some(digit) = khar.to_digit(10) {
+                digits.push(digit as u8);
+            } else if !DEFAULT_SEPARATORS.contains(&khar) {
+                problems.push(SINProblem::InvalidCharacter {
+                    position: position + leading,
+                    character: khar,
+                });
+            }
+        }
+        match Self::digit_vec_to_array(digits) {
+            Err(SINParseError::TooShort) => problems.push(SINProblem::TooShort),
+            Err(_) => problems.push(SINProblem::TooLong),
+            Ok(digits) => {
+                if !luhn::is_valid(&digits) {
+                    problems.push(SINProblem::InvalidChecksum);
+                }
+                if PLACEHOLDERS.contains(&digits) {
+                    problems.push(SINProblem::Placeholder);
+                }
+            }
+        }
+        problems
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn accumulates_problems() {
+        assert_eq!(
+            SIN::check(" 000 000 000x1 ".to_string()),
+            vec![
+                SINProblem::InvalidCharacter {
+                    position: 12,
+                    character: 'x'
+                },
+                SINProblem::TooLong,
+            ]
+        );
+        assert_eq!(
+            SIN::check("123456782".to_string()),
+            vec![SINProblem::Placeholder]
+        );
+        assert_eq!(
+            SIN::check("123456789".to_string()),
+            vec![SINProblem::InvalidChecksum]
+        );
+        assert_eq!(SIN::check("".to_string()), vec![SINProblem::TooShort]);
+    }
+}
@@ -0,0 +1,227 @@
+//! Other account numbers assigned by the Canada Revenue Agency, and errors shared by tax account
+//! numbers.
+
+use crate::SIN;
+use std::{convert::TryInto, error::Error, fmt, str::FromStr};
+
+/// An error resulting from parsing a CRA account number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AccountNumberParseError {
+    /// The account number has too many digits.
+    TooLong,
+    /// The account number doesn't have enough digits.
+    TooShort,
+    /// The account number doesn't start with the right letters.
+    InvalidPrefix,
+    /// The account number has a character that isn't allowed after the prefix.
+    InvalidCharacter,
+    /// The account number doesn't have the right program identifier.
+    InvalidProgram,
+    /// The reference number isn't allowed.
+    InvalidReference,
+    /// The account number has an invalid checksum.
+    InvalidChecksum,
+}
+
+impl fmt::Display for AccountNumberParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::TooLong => "account number has too many digits",
+            Self::TooShort => "account number doesn't have enough digits",
+            Self::InvalidPrefix => "account number has an invalid prefix",
+            Self::InvalidCharacter => "account number has an invalid character",
+            Self::InvalidProgram => "account number has an invalid program identifier",
+            Self::InvalidReference => "account number has an invalid reference number",
+            Self::InvalidChecksum => "account number has an invalid checksum",
+        })
+    }
+}
+
+impl Error for AccountNumberParseError {}
+
+/// Splits an account number into the prefix letters (in uppercase) and the digits. Spaces and
+/// dashes are ignored.
+fn split_account_number(s: &str) -> Result<(String, Vec<u8>), AccountNumberParseError> {
+    let s: String = s
+        .chars()
+        .filter(|&c| c != ' ' && c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    let split = s.find(|c: char| !c.is_ascii_uppercase()).unwrap_or(s.len());
+    let (prefix, digits) = s.split_at(split);
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(AccountNumberParseError::InvalidCharacter);
+    }
+    Ok((prefix.to_string(), SIN::extract_digits(digits)))
+}
+
+/// Checks that `digits` has exactly `len` digits.
+pub(crate) fn check_length(digits: &[u8], len: usize) -> Result<(), AccountNumberParseError> {
+    match digits.len() {
+        n if n < len => Err(AccountNumberParseError::TooShort),
+        n if n > len => Err(AccountNumberParseError::TooLong),
+        _ => Ok(()),
+    }
+}
+
+/// A trust account number, which is a `T` followed by 8 digits, like `T12345678`. These are
+/// assigned to trusts that file T3 returns.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{AccountNumberParseError, TrustAccountNumber};
+/// let number = TrustAccountNumber::parse("t-1234 5678".to_string()).unwrap();
+/// assert_eq!(number.to_string(), "T12345678");
+/// assert_eq!(
+///     TrustAccountNumber::parse("12345678".to_string()),
+///     Err(AccountNumberParseError::InvalidPrefix),
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct TrustAccountNumber {
+    digits: [u8; 8],
+}
+
+impl TrustAccountNumber {
+    /// Parses a trust account number. Spaces and dashes are ignored, and the `T` can be in either
+    /// case.
+    pub fn parse(s: String) -> Result<Self, AccountNumberParseError> {
+        let (prefix, digits) = split_account_number(&s)?;
+        if prefix != "T" {
+            return Err(AccountNumberParseError::InvalidPrefix);
+        }
+        check_length(&digits, 8)?;
+        Ok(Self {
+            digits: digits.try_into().unwrap(),
+        })
+    }
+    /// Returns the 8 digits after the `T`.
+    pub fn digits(self) -> [u8; 8] {
+        self.digits
+    }
+}
+
+impl FromStr for TrustAccountNumber {
+    type Err = AccountNumberParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.to_string())
+    }
+}
+
+impl fmt::Display for TrustAccountNumber {
+    /// Formats the account number like `T12345678`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("T")?;
+        for digit in &self.digits {
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+/// A non-resident account number, used by payers that withhold Part XIII tax on amounts paid to
+/// non-residents. It is 3 letters starting with `NR` followed by 6 digits, like `NRA123456`.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::NonResidentAccountNumber;
+/// let number = NonResidentAccountNumber::parse("nrb 123456".to_string()).unwrap();
+/// assert_eq!(number.series(), 'B');
+/// assert_eq!(number.to_string(), "NRB123456");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct NonResidentAccountNumber {
+    series: u8,
+    digits: [u8; 6],
+}
+
+impl NonResidentAccountNumber {
+    /// Parses a non-resident account number. Spaces and dashes are ignored, and the letters can be
+    /// in either case.
+    pub fn parse(s: String) -> Result<Self, AccountNumberParseError> {
+        let (prefix, digits) = split_account_number(&s)?;
+        let series = match prefix.as_bytes() {
+            [b'N', b'R', series] => *series,
+            _ => return Err(AccountNumberParseError::InvalidPrefix),
+        };
+        check_length(&digits, 6)?;
+        Ok(Self {
+            series,
+            digits: digits.try_into().unwrap(),
+        })
+    }
+    /// The letter after `NR`.
+    pub fn series(self) -> char {
+        self.series as char
+    }
+    /// Returns the 6 digits after the letters.
+    pub fn digits(self) -> [u8; 6] {
+        self.digits
+    }
+}
+
+impl FromStr for NonResidentAccountNumber {
+    type Err = AccountNumberParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.to_string())
+    }
+}
+
+impl fmt::Display for NonResidentAccountNumber {
+    /// Formats the account number like `NRA123456`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NR{}", self.series())?;
+        for digit in &self.digits {
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_trust() {
+        assert!("T12345678".parse::<TrustAccountNumber>().is_ok());
+        assert_eq!(
+            TrustAccountNumber::parse("T1234567".to_string()),
+            Err(AccountNumberParseError::TooShort)
+        );
+        assert_eq!(
+            TrustAccountNumber::parse("T123456789".to_string()),
+            Err(AccountNumberParseError::TooLong)
+        );
+        assert_eq!(
+            TrustAccountNumber::parse("TT12345678".to_string()),
+            Err(AccountNumberParseError::InvalidPrefix)
+        );
+        assert_eq!(
+            TrustAccountNumber::parse("T1234A5678".to_string()),
+            Err(AccountNumberParseError::InvalidCharacter)
+        );
+        assert_eq!(
+            TrustAccountNumber::parse("T1234é5678".to_string()),
+            Err(AccountNumberParseError::InvalidCharacter)
+        );
+    }
+
+    #[test]
+    fn parse_non_resident() {
+        assert!("NRA123456".parse::<NonResidentAccountNumber>().is_ok());
+        for s in ["NR123456", "NRAB123456", "NXA123456", "123456"] {
+            assert_eq!(
+                NonResidentAccountNumber::parse(s.to_string()),
+                Err(AccountNumberParseError::InvalidPrefix)
+            );
+        }
+        assert_eq!(
+            NonResidentAccountNumber::parse("NRA12345".to_string()),
+            Err(AccountNumberParseError::TooShort)
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Bulk validation of SINs in CSV files.
+
+use crate::{Locale, SIN};
+use std::{error::Error, fmt, io};
+
+/// An error resulting from validating a CSV file.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CsvValidationError {
+    /// The CSV file doesn't have a column with the given name.
+    MissingColumn,
+    /// The CSV file couldn't be read or written.
+    Csv(::csv::Error),
+}
+
+impl fmt::Display for CsvValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingColumn => f.write_str("CSV file doesn't have the SIN column"),
+            Self::Csv(err) => write!(f, "CSV error: {}", err),
+        }
+    }
+}
+
+impl Error for CsvValidationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::MissingColumn => None,
+            Self::Csv(err) => Some(err),
+        }
+    }
+}
+
+impl From<::csv::Error> for CsvValidationError {
+    fn from(err: ::csv::Error) -> Self {
+        Self::Csv(err)
+    }
+}
+
+/// Reads a CSV file with a header row from `reader`, validates the SINs in the column named
+/// `column`, and writes each row to `writer` with three extra columns:
+/// - `sin_valid`: `true` or `false`
+/// - `sin_types`: the types the SIN could be (see [`SIN::types`]), separated by `;`
+/// - `sin_error`: why the SIN is invalid
+///
+/// Rows are written as they are read, so large files don't need to fit in memory.
+///
+/// ## Examples
+/// ```
+/// let input = "name,sin\nAlice,046-454-286\nBob,123\n";
+/// let mut output = Vec::new();
+/// canada_sin::csv::validate(input.as_bytes(), &mut output, "sin").unwrap();
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "name,sin,sin_valid,sin_types,sin_error\n\
+///      Alice,046-454-286,true,CRA-assigned,\n\
+///      Bob,123,false,,SIN is less than 9 digits\n",
+/// );
+/// ```
+pub fn validate<R: io::Read, W: io::Write>(
+    reader: R,
+    writer: W,
+    column: &str,
+) -> Result<(), CsvValidationError> {
+    let mut reader = ::csv::Reader::from_reader(reader);
+    let mut writer = ::csv::Writer::from_writer(writer);
+    let mut headers = reader.headers()?.clone();
+    let idx = headers
+        .iter()
+        .position(|header| header == column)
+        .ok_or(CsvValidationError::MissingColumn)?;
+    headers.push_field("sin_valid");
+    headers.push_field("sin_types");
+    headers.push_field("sin_error");
+    writer.write_record(&headers)?;
+    for record in reader.records() {
+        let mut record = record?;
+        match SIN::parse(record.get(idx).unwrap_or_default()) {
+            Ok(sin) => {
+                let types: Vec<_> = sin
+                    .types()
+                    .iter()
+                    .map(|ty| ty.name(Locale::English))
+                    .collect();
+                record.push_field("true");
+                record.push_field(&types.join(";"));
+                record.push_field("");
+            }
+            Err(err) => {
+                record.push_field("false");
+                record.push_field("");
+                record.push_field(&err.to_string());
+            }
+        }
+        writer.write_record(&record)?;
+    }
+    writer.flush().map_err(::csv::Error::from)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_column() {
+        let mut output = Vec::new();
+        assert!(matches!(
+            validate("name\nAlice\n".as_bytes(), &mut output, "sin"),
+            Err(CsvValidationError::MissingColumn)
+        ));
+    }
+}
@@ -0,0 +1,62 @@
+//! Calendar dates, for identifiers that contain or come with dates.
+
+use core::fmt;
+
+/// A date in the Gregorian calendar.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::Date;
+/// let date = Date::new(1985, 2, 28).unwrap();
+/// assert_eq!(date.to_string(), "1985-02-28");
+/// assert!(Date::new(1985, 2, 29).is_none());
+/// assert!(Date::new(1984, 2, 29).unwrap() < date);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+fn is_leap_year(year: u16) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl Date {
+    /// Creates a date, returning `None` if it doesn't exist.
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+    /// The year.
+    pub fn year(self) -> u16 {
+        self.year
+    }
+    /// The month, from 1 to 12.
+    pub fn month(self) -> u8 {
+        self.month
+    }
+    /// The day of the month, from 1 to 31.
+    pub fn day(self) -> u8 {
+        self.day
+    }
+}
+
+impl fmt::Display for Date {
+    /// Formats the date in ISO 8601 format, like `1985-02-28`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
@@ -0,0 +1,90 @@
+//! Logging SINs and parse errors with `defmt`, for firmware that logs over RTT. SINs are logged
+//! with only their last 3 digits, like `***-***-286`, so logs can be shared safely. Wrap a SIN in
+//! [`Unmasked`] to log all of its digits.
+//!
+//! This module is only available without the `std` feature, since `defmt` can only be linked into
+//! firmware.
+
+use crate::{FormatOptions, SINParseError, SIN};
+use ::defmt::{write, Display2Format, Format, Formatter};
+use core::fmt;
+
+/// Displays a SIN like [`SIN::masked`], without allocating.
+struct Masked(SIN);
+
+impl fmt::Display for Masked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.write_with(&FormatOptions::masked(), f)
+    }
+}
+
+impl Format for SIN {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{}", Display2Format(&Masked(*self)))
+    }
+}
+
+/// A SIN that is logged with all of its digits, like `046-454-286`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Unmasked(pub SIN);
+
+impl Format for Unmasked {
+    fn format(&self, f: Formatter<'_>) {
+        let [a, b, c, d, e, g, h, i, j] = self.0.digits();
+        write!(
+            f,
+            "{=u8}{=u8}{=u8}-{=u8}{=u8}{=u8}-{=u8}{=u8}{=u8}",
+            a, b, c, d, e, g, h, i, j
+        )
+    }
+}
+
+/// Implements `Format` for [`SINParseError`] with the English description of each variant. The
+/// descriptions have to be string literals to be interned, so they are listed again here, and
+/// the tests check them against [`SINParseError::message`].
+macro_rules! format_parse_errors {
+    ($($variant:ident => $message:literal,)*) => {
+        impl Format for SINParseError {
+            /// Logs the English description of the error. The descriptions are interned, so only
+            /// an index is sent over the wire.
+            fn format(&self, f: Formatter<'_>) {
+                match self {
+                    $(Self::$variant => write!(f, $message),)*
+                }
+            }
+        }
+
+        #[cfg(test)]
+        const MESSAGES: &[(SINParseError, &str)] = &[$((SINParseError::$variant, $message),)*];
+    };
+}
+
+format_parse_errors! {
+    TooLong => "SIN is longer than 9 digits",
+    TooShort => "SIN is less than 9 digits",
+    InvalidChecksum => "SIN has an invalid checksum",
+    InvalidDigit => "SIN contains a digit greater than 9",
+    Placeholder => "SIN is a placeholder value",
+    WrongType => "SIN is not of the expected type",
+    InvalidCharacter => "SIN contains an invalid character",
+    MisplacedSeparator => "SIN has a separator in the wrong place",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Locale;
+
+    #[test]
+    fn messages_match() {
+        for &(err, message) in MESSAGES {
+            assert_eq!(err.message(Locale::English), message);
+        }
+    }
+
+    #[test]
+    fn masked() {
+        let sin = SIN::parse("046454286").unwrap();
+        assert_eq!(Masked(sin).to_string(), "***-***-286");
+    }
+}
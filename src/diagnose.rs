@@ -0,0 +1,238 @@
+//! Detailed information about why a SIN fails its checksum.
+
+use crate::{luhn, parse_options::DEFAULT_SEPARATORS, SINParseError, SIN};
+use std::convert::TryInto;
+
+/// A change to a single digit of a SIN.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DigitCorrection {
+    /// The position of the digit to change, from 0 to 8.
+    pub position: usize,
+    /// The digit to put in that position.
+    pub digit: u8,
+}
+
+/// Information about the Luhn checksum of a 9-digit number, as returned by [`SIN::diagnose`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecksumDiagnosis {
+    /// The Luhn sum of all 9 digits. The checksum is valid if this is a multiple of 10.
+    pub luhn_sum: u32,
+    /// The last digit that would make the checksum valid, given the first 8 digits.
+    pub expected_check_digit: u8,
+    /// Every way of changing a single digit that makes the checksum valid. This is empty if the
+    /// checksum is already valid, and otherwise has one correction for each position.
+    pub corrections: Vec<DigitCorrection>,
+}
+
+impl ChecksumDiagnosis {
+    /// Is the checksum valid?
+    pub fn is_valid(&self) -> bool {
+        self.luhn_sum % 10 == 0
+    }
+}
+
+impl SIN {
+    /// Explains the checksum of a 9-digit number, which is useful for finding out why
+    /// [`SIN::parse`] returned [`SINParseError::InvalidChecksum`]. Numbers that aren't 9 digits
+    /// long produce the same errors as [`SIN::parse`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{DigitCorrection, SIN};
+    /// let diagnosis = SIN::diagnose("046-454-287".to_string()).unwrap();
+    /// assert!(!diagnosis.is_valid());
+    /// assert_eq!(diagnosis.luhn_sum, 51);
+    /// assert_eq!(diagnosis.expected_check_digit, 6);
+    /// assert!(diagnosis.corrections.contains(&DigitCorrection { position: 8, digit: 6 }));
+    /// ```
+    pub fn diagnose(s: impl AsRef<str>) -> Result<ChecksumDiagnosis, SINParseError> {
+        let digits = Self::parse_digits(s.as_ref())?;
+        let luhn_sum = luhn::sum(&digits);
+        let mut corrections = Vec::new();
+        if luhn_sum % 10 != 0 {
+            for position in 0..9 {
+                let mut candidate = digits;
+                for digit in 0..=9 {
+                    candidate[position] = digit;
+                    if digit != digits[position] && luhn::is_valid(&candidate) {
+                        corrections.push(DigitCorrection { position, digit });
+                    }
+                }
+            }
+        }
+        Ok(ChecksumDiagnosis {
+            luhn_sum,
+            expected_check_digit: luhn::check_digit(&digits[..8]),
+            corrections,
+        })
+    }
+    /// Finds valid SINs that are one typo away from the input, so that users can be asked "did you
+    /// mean ...?". A typo is changing a single digit, swapping two adjacent digits, adding a digit,
+    /// or removing a digit. The input itself is never included.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// let suggestions = SIN::suggest_corrections("046-454-826".to_string());
+    /// assert!(suggestions.contains(&SIN::parse("046454286".to_string()).unwrap()));
+    /// assert!(suggestions.iter().all(|sin| sin.digits_string() != "046454826"));
+    /// ```
+    pub fn suggest_corrections(s: impl AsRef<str>) -> Vec<SIN> {
+        let digits = Self::extract_digits(s.as_ref());
+        let mut candidates: Vec<Vec<u8>> = Vec::new();
+        match digits.len() {
+            8 => {
+                for position in 0..=8 {
+                    for digit in 0..=9 {
+                        let mut candidate = digits.clone();
+                        candidate.insert(position, digit);
+                        candidates.push(candidate);
+                    }
+                }
+            }
+            9 => {
+                for position in 0..9 {
+                    for digit in 0..=9 {
+                        let mut candidate = digits.clone();
+                        candidate[position] = digit;
+                        candidates.push(candidate);
+                    }
+                }
+                for position in 0..8 {
+                    let mut candidate = digits.clone();
+                    candidate.swap(position, position + 1);
+                    candidates.push(candidate);
+                }
+            }
+            10 => {
+                for position in 0..10 {
+                    let mut candidate = digits.clone();
+                    candidate.remove(position);
+                    candidates.push(candidate);
+                }
+            }
+            _ => {}
+        }
+        let mut suggestions: Vec<SIN> = Vec::new();
+        for candidate in candidates {
+            if candidate != digits && luhn::is_valid(&candidate) {
+                let sin = SIN::from_valid_digits(candidate.try_into().unwrap());
+                if !suggestions.contains(&sin) {
+                    suggestions.push(sin);
+                }
+            }
+        }
+        suggestions
+    }
+    /// Estimates how far a string is from being a valid SIN, as the smallest number of characters
+    /// that need to be added, removed or changed. Whitespace and the separators allowed by
+    /// [`ParseOptions::new`](crate::ParseOptions::new) don't count, and every other character
+    /// that isn't a digit needs to be removed or changed into a digit. This is useful for data
+    /// quality reports, where a distance of 1 is probably a typo, but a large distance means the
+    /// field has something else in it.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// assert_eq!(SIN::checksum_distance("046-454-286"), 0);
+    /// assert_eq!(SIN::checksum_distance("046-454-287"), 1);
+    /// assert_eq!(SIN::checksum_distance("046-454"), 3);
+    /// assert_eq!(SIN::checksum_distance("n/a"), 9);
+    /// ```
+    pub fn checksum_distance(s: &str) -> usize {
+        let mut digits = Vec::with_capacity(9);
+        let mut junk = 0;
+        for khar in s.chars() {
+            if khar.is_ascii_digit() {
+                digits.push(khar as u8 - b'0');
+            } else if !khar.is_whitespace() && !DEFAULT_SEPARATORS.contains(&khar) {
+                junk += 1;
+            }
+        }
+        match digits.len() {
+            // junk characters can be changed into the missing digits, and a digit that's added or
+            // changed can always be chosen to fix the checksum
+            len @ 0..=8 => junk.max(9 - len),
+            9 if luhn::is_valid(&digits) => junk,
+            9 => junk + 1,
+            // the junk characters and extra digits need to be removed
+            len if has_valid_subsequence(&digits) => junk + len - 9,
+            len => junk + len - 9 + 1,
+        }
+    }
+}
+
+/// Can 9 of the digits be picked, in order, to make a valid SIN?
+fn has_valid_subsequence(digits: &[u8]) -> bool {
+    // reachable[count][sum] is whether `count` digits can be picked with a Luhn sum of `sum`,
+    // modulo 10
+    let mut reachable = [[false; 10]; 10];
+    reachable[0][0] = true;
+    for &digit in digits {
+        for count in (0..9).rev() {
+            let value = if count % 2 == 1 {
+                luhn::DOUBLED[usize::from(digit)]
+            } else {
+                digit
+            };
+            for sum in 0..10 {
+                if reachable[count][sum] {
+                    reachable[count + 1][(sum + usize::from(value)) % 10] = true;
+                }
+            }
+        }
+    }
+    reachable[9][0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn corrections_are_valid() {
+        let diagnosis = SIN::diagnose("123456789".to_string()).unwrap();
+        assert_eq!(diagnosis.corrections.len(), 9);
+        for correction in diagnosis.corrections {
+            let mut digits = *b"123456789";
+            digits[correction.position] = b'0' + correction.digit;
+            let s = String::from_utf8(digits.to_vec()).unwrap();
+            assert!(SIN::parse(s).is_ok());
+        }
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn valid_has_no_corrections() {
+        let diagnosis = SIN::diagnose("046454286".to_string()).unwrap();
+        assert!(diagnosis.is_valid());
+        assert!(diagnosis.corrections.is_empty());
+        assert_eq!(
+            SIN::diagnose("123".to_string()),
+            Err(SINParseError::TooShort)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn suggestions_for_lengths() {
+        let sin = SIN::parse("046454286".to_string()).unwrap();
+        assert!(SIN::suggest_corrections("04645428".to_string()).contains(&sin));
+        assert!(SIN::suggest_corrections("0464542866".to_string()).contains(&sin));
+        assert!(SIN::suggest_corrections("046454288".to_string()).contains(&sin));
+        assert!(SIN::suggest_corrections("123".to_string()).is_empty());
+        assert!(!SIN::suggest_corrections("046454286".to_string()).contains(&sin));
+    }
+
+    #[test]
+    fn checksum_distances() {
+        assert_eq!(SIN::checksum_distance(""), 9);
+        assert_eq!(SIN::checksum_distance("0464542860"), 1);
+        assert_eq!(SIN::checksum_distance("1111111111"), 2);
+        assert_eq!(SIN::checksum_distance("046454286 x"), 1);
+        assert_eq!(SIN::checksum_distance("04645428x"), 1);
+        assert_eq!(SIN::checksum_distance("O46454286"), 1);
+        assert_eq!(SIN::checksum_distance("5555555555555"), 5);
+    }
+}
@@ -0,0 +1,44 @@
+//! Hashing SINs for storage, with SHA-256.
+
+use crate::SIN;
+use sha2::{Digest, Sha256};
+
+impl SIN {
+    /// Hashes the SIN with SHA-256, for storing SINs at rest in a form that can still be looked
+    /// up. The hash is of `salt` followed by the 9 digits of the SIN, without separators, so
+    /// `046-454-286` and `046 454 286` always give the same digest.
+    ///
+    /// There are only 100 million valid SINs, so anyone who knows the salt can find the SIN for a
+    /// digest by trying them all. The salt must be kept secret, like a key, and stored separately
+    /// from the digests. Using one salt for a whole dataset keeps digests joinable within it;
+    /// using different salts for different datasets keeps them unlinkable.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// let dashed = SIN::parse("046-454-286".to_string()).unwrap();
+    /// let plain = SIN::parse("046454286".to_string()).unwrap();
+    /// assert_eq!(dashed.digest(b"secret salt"), plain.digest(b"secret salt"));
+    /// assert_ne!(dashed.digest(b"secret salt"), dashed.digest(b"other salt"));
+    /// ```
+    pub fn digest(&self, salt: &[u8]) -> [u8; 32] {
+        let mut buf = [0; 11];
+        let mut hasher = Sha256::new();
+        hasher.update(salt);
+        hasher.update(self.format_into_buffer(&mut buf, false));
+        hasher.finalize().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn digest_of_digits() {
+        let sin = SIN::parse("046454286".to_string()).unwrap();
+        let expected: [u8; 32] = Sha256::digest(b"salt046454286").into();
+        assert_eq!(sin.digest(b"salt"), expected);
+    }
+}
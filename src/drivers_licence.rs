@@ -0,0 +1,298 @@
+//! Driver's licence numbers. Each province issues its own driver's licences, with its own number
+//! format. Provinces don't publish check digit algorithms for their licence numbers, so only the
+//! format is checked.
+//!
+//! ## Examples
+//! ```
+//! use canada_sin::{drivers_licence::DriversLicence, SINType};
+//! let licence = DriversLicence::parse("A1234-56789-01234".to_string(), SINType::Ontario).unwrap();
+//! assert_eq!(licence.number(), "A12345678901234");
+//! assert!(DriversLicence::parse("1234567".to_string(), SINType::Ontario).is_err());
+//! assert!(DriversLicence::parse("1234567".to_string(), SINType::BritishColumbia).is_ok());
+//! ```
+
+use crate::{health::Sex, names::name_letters, Date, SINType};
+use std::{error::Error, fmt};
+
+/// An error resulting from parsing a driver's licence number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DriversLicenceParseError {
+    /// The number doesn't match the province's format.
+    InvalidFormat,
+    /// Driver's licences from this province (or from something that isn't a province) aren't
+    /// supported.
+    UnsupportedProvince,
+}
+
+impl fmt::Display for DriversLicenceParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidFormat => "driver's licence number has an invalid format",
+            Self::UnsupportedProvince => "driver's licences from this province are not supported",
+        })
+    }
+}
+
+impl Error for DriversLicenceParseError {}
+
+/// Part of a driver's licence number format.
+#[derive(Debug, Copy, Clone)]
+enum Segment {
+    /// Between the minimum and maximum number of letters.
+    Letters(usize, usize),
+    /// Between the minimum and maximum number of digits.
+    Digits(usize, usize),
+}
+
+/// The format of driver's licence numbers from a province, without separators. Letters and digits
+/// always alternate, so each segment can be matched greedily.
+fn format(province: SINType) -> Option<&'static [Segment]> {
+    use SINType::*;
+    use Segment::*;
+    Some(match province {
+        Alberta => &[Digits(6, 9)],
+        BritishColumbia => &[Digits(7, 7)],
+        Manitoba => &[Letters(7, 7), Digits(3, 3), Letters(2, 2)],
+        NewBrunswick => &[Digits(5, 7)],
+        NewfoundlandLabrador => &[Letters(1, 1), Digits(9, 9)],
+        NorthwestTerritories | Nunavut => &[Digits(6, 6)],
+        NovaScotia => &[Letters(5, 5), Digits(9, 9)],
+        Ontario => &[Letters(1, 1), Digits(14, 14)],
+        PrinceEdwardIsland | Yukon => &[Digits(1, 6)],
+        Quebec => &[Letters(1, 1), Digits(12, 12)],
+        Saskatchewan => &[Digits(8, 8)],
+        _ => return None,
+    })
+}
+
+/// Checks if `s` matches the format.
+fn matches_format(s: &str, format: &[Segment]) -> bool {
+    let mut rest = s;
+    for segment in format {
+        let (min, max, class): (_, _, fn(&char) -> bool) = match *segment {
+            Segment::Letters(min, max) => (min, max, char::is_ascii_uppercase),
+            Segment::Digits(min, max) => (min, max, char::is_ascii_digit),
+        };
+        let len = rest.chars().take_while(class).count();
+        if len < min || len > max {
+            return false;
+        }
+        rest = &rest[len..];
+    }
+    rest.is_empty()
+}
+
+/// A driver's licence number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriversLicence {
+    province: SINType,
+    number: String,
+}
+
+impl DriversLicence {
+    /// Parses a driver's licence number issued by `province`. Spaces and dashes are ignored, and
+    /// letters can be in either case.
+    pub fn parse(s: String, province: SINType) -> Result<Self, DriversLicenceParseError> {
+        let format = format(province).ok_or(DriversLicenceParseError::UnsupportedProvince)?;
+        let number: String = s
+            .chars()
+            .filter(|&c| c != ' ' && c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        if !matches_format(&number, format) {
+            return Err(DriversLicenceParseError::InvalidFormat);
+        }
+        Ok(Self { province, number })
+    }
+    /// The province that issued the licence.
+    pub fn province(&self) -> SINType {
+        self.province
+    }
+    /// The licence number, in uppercase without separators.
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+}
+
+/// The information encoded in an Ontario driver's licence number, like `T6524-79308-51225`. It is
+/// made of:
+/// - the first letter of the surname
+/// - 4 digits based on the surname, and 4 digits based on the given names
+/// - the last 2 digits of the year of birth
+/// - the month of birth, plus 50 for women
+/// - the day of birth
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct OntarioLicenceDetails {
+    /// The first letter of the surname.
+    pub surname_initial: char,
+    /// The last 2 digits of the year of birth.
+    pub birth_year_digits: u8,
+    /// The month of birth, from 1 to 12.
+    pub birth_month: u8,
+    /// The day of birth.
+    pub birth_day: u8,
+    /// The sex of the licence holder.
+    pub sex: Sex,
+}
+
+impl DriversLicence {
+    /// Decodes the information in an Ontario driver's licence number. This returns `None` for
+    /// licences from other provinces, or if the birth date isn't valid.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{drivers_licence::DriversLicence, health::Sex, SINType};
+    /// let licence = DriversLicence::parse("T6524-79308-51225".to_string(), SINType::Ontario)
+    ///     .unwrap();
+    /// let details = licence.ontario_details().unwrap();
+    /// assert_eq!(details.surname_initial, 'T');
+    /// assert_eq!(details.birth_year_digits, 85);
+    /// assert_eq!(details.birth_month, 12);
+    /// assert_eq!(details.birth_day, 25);
+    /// assert_eq!(details.sex, Sex::Male);
+    /// ```
+    pub fn ontario_details(&self) -> Option<OntarioLicenceDetails> {
+        if self.province != SINType::Ontario {
+            return None;
+        }
+        let pair = |idx: usize| self.number[idx..idx + 2].parse::<u8>().unwrap();
+        let (birth_month, sex) = match pair(11) {
+            month @ 1..=12 => (month, Sex::Male),
+            month @ 51..=62 => (month - 50, Sex::Female),
+            _ => return None,
+        };
+        let (birth_year_digits, birth_day) = (pair(9), pair(13));
+        // the century isn't known, so the date only has to exist in one of them
+        let year_in = |century: u16| {
+            Date::new(
+                century + u16::from(birth_year_digits),
+                birth_month,
+                birth_day,
+            )
+        };
+        year_in(1900).or_else(|| year_in(2000))?;
+        Some(OntarioLicenceDetails {
+            surname_initial: self.number.as_bytes()[0] as char,
+            birth_year_digits,
+            birth_month,
+            birth_day,
+            sex,
+        })
+    }
+    /// Checks if the surname initial and birth date encoded in an Ontario driver's licence number
+    /// match a person. This returns `None` if the licence doesn't encode that information, which
+    /// is the case for licences from other provinces.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{drivers_licence::DriversLicence, Date, SINType};
+    /// let licence = DriversLicence::parse("T6524-79308-51225".to_string(), SINType::Ontario)
+    ///     .unwrap();
+    /// let birth_date = Date::new(1985, 12, 25).unwrap();
+    /// assert_eq!(licence.matches_person("Tremblay", birth_date), Some(true));
+    /// assert_eq!(licence.matches_person("Gagnon", birth_date), Some(false));
+    /// assert_eq!(
+    ///     licence.matches_person("Tremblay", Date::new(1985, 12, 24).unwrap()),
+    ///     Some(false),
+    /// );
+    /// ```
+    pub fn matches_person(&self, surname: &str, birth_date: Date) -> Option<bool> {
+        let details = self.ontario_details()?;
+        Some(
+            name_letters(surname).next() == Some(details.surname_initial)
+                && (birth_date.year() % 100) as u8 == details.birth_year_digits
+                && birth_date.month() == details.birth_month
+                && birth_date.day() == details.birth_day,
+        )
+    }
+}
+
+impl fmt::Display for DriversLicence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats() {
+        use SINType::*;
+        let valid = [
+            ("123456", Alberta),
+            ("123456-789", Alberta),
+            ("abcdefg123hi", Manitoba),
+            ("A123456789", NewfoundlandLabrador),
+            ("SMITH 123456789", NovaScotia),
+            ("Q1234-567890-12", Quebec),
+            ("1", Yukon),
+        ];
+        for (s, province) in valid {
+            assert!(
+                DriversLicence::parse(s.to_string(), province).is_ok(),
+                "{}",
+                s
+            );
+        }
+        let invalid = [
+            ("12345", Alberta),
+            ("1234567890", Alberta),
+            ("ABCDEF1234HI", Manitoba),
+            ("1234567", Ontario),
+            ("A1234-56789-0123", Ontario),
+            ("A1234-56789-0123é", Ontario),
+            ("1234567", Yukon),
+            ("", Yukon),
+        ];
+        for (s, province) in invalid {
+            assert_eq!(
+                DriversLicence::parse(s.to_string(), province),
+                Err(DriversLicenceParseError::InvalidFormat),
+                "{}",
+                s
+            );
+        }
+        assert_eq!(
+            DriversLicence::parse("123".to_string(), OverseasForces),
+            Err(DriversLicenceParseError::UnsupportedProvince)
+        );
+    }
+
+    #[test]
+    fn ontario_details() {
+        let licence = DriversLicence::parse("É6524-79308-55228".to_string(), SINType::Ontario);
+        assert_eq!(licence, Err(DriversLicenceParseError::InvalidFormat));
+        let licence =
+            DriversLicence::parse("e6524-79308-55228".to_string(), SINType::Ontario).unwrap();
+        let details = licence.ontario_details().unwrap();
+        assert_eq!(details.sex, Sex::Female);
+        assert_eq!(details.birth_month, 2);
+        let birth_date = Date::new(1985, 2, 28).unwrap();
+        assert_eq!(licence.matches_person("Émond", birth_date), Some(true));
+        assert_eq!(
+            licence.matches_person("Émond", Date::new(1985, 2, 27).unwrap()),
+            Some(false)
+        );
+        let licence =
+            DriversLicence::parse("E6524-79308-51329".to_string(), SINType::Ontario).unwrap();
+        assert_eq!(licence.ontario_details(), None);
+        assert_eq!(licence.matches_person("Émond", birth_date), None);
+        for number in [
+            "E6524-79308-50230",
+            "E6524-79308-60431",
+            "E6524-79308-50000",
+        ] {
+            let licence = DriversLicence::parse(number.to_string(), SINType::Ontario).unwrap();
+            assert_eq!(licence.ontario_details(), None);
+        }
+        let licence =
+            DriversLicence::parse("E6524-79300-00229".to_string(), SINType::Ontario).unwrap();
+        assert_eq!(licence.ontario_details().unwrap().birth_day, 29);
+        let licence =
+            DriversLicence::parse("1234567".to_string(), SINType::BritishColumbia).unwrap();
+        assert_eq!(licence.ontario_details(), None);
+    }
+}
@@ -0,0 +1,130 @@
+//! Human-readable explanations of what a SIN's digits mean.
+
+use crate::{Locale, SINType, SIN};
+use std::fmt;
+
+/// Why a SIN is classified the way it is, as returned by [`SIN::explanation`]. The `Display`
+/// implementation produces the same text as [`SIN::explain`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Explanation {
+    /// The first digit, which determines the types.
+    pub first_digit: u8,
+    /// All types the SIN could be, from [`SIN::types`].
+    pub types: Vec<SINType>,
+    /// Is the SIN a placeholder value that is never issued (see [`SIN::is_placeholder`])?
+    pub placeholder: bool,
+}
+
+/// Joins phrases like "A, B or C".
+fn join_or(phrases: &[&str]) -> String {
+    match phrases.split_last() {
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => String::new(),
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let provinces: Vec<&str> = self
+            .types
+            .iter()
+            .filter(|ty| ty.is_province())
+            .map(|ty| ty.name(Locale::English))
+            .collect();
+        let mut phrases = Vec::new();
+        if !provinces.is_empty() {
+            phrases.push(format!("issued in {}", join_or(&provinces)));
+        }
+        for ty in &self.types {
+            phrases.push(
+                match ty {
+                    SINType::CRAAssigned => "is a tax number assigned by the CRA",
+                    SINType::TemporaryResident => "issued to a temporary resident",
+                    SINType::OverseasForces => "issued to military forces abroad",
+                    SINType::BusinessNumber => "is a business number",
+                    _ => continue,
+                }
+                .to_string(),
+            );
+        }
+        // every SIN has a valid checksum, but support staff often need to hear that it passes
+        write!(
+            f,
+            "Starts with {}: {}; Luhn check passes",
+            self.first_digit,
+            phrases.join(", or "),
+        )?;
+        if self.placeholder {
+            f.write_str("; placeholder value that is never issued")?;
+        }
+        f.write_str(".")
+    }
+}
+
+impl SIN {
+    /// Explains how the SIN is classified, in English prose that can be shown to support staff.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// assert_eq!(
+    ///     SIN::parse("734323843".to_string()).unwrap().explain(),
+    ///     "Starts with 7: issued in British Columbia or Yukon, or is a business number; Luhn \
+    ///      check passes.",
+    /// );
+    /// ```
+    pub fn explain(&self) -> String {
+        self.explanation().to_string()
+    }
+    /// Explains how the SIN is classified, as a struct that can be rendered differently.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{SINType, SIN};
+    /// let explanation = SIN::parse("346454382".to_string()).unwrap().explanation();
+    /// assert_eq!(explanation.first_digit, 3);
+    /// assert_eq!(explanation.types, vec![SINType::Quebec]);
+    /// ```
+    pub fn explanation(&self) -> Explanation {
+        Explanation {
+            first_digit: self.first_digit(),
+            types: self.types(),
+            placeholder: self.is_placeholder(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::unnecessary_to_owned)]
+    fn explain(s: &str) -> String {
+        SIN::parse(s.to_string()).unwrap().explain()
+    }
+
+    #[test]
+    fn explanations() {
+        assert_eq!(
+            explain("000000000"),
+            "Starts with 0: is a tax number assigned by the CRA; Luhn check passes; placeholder \
+             value that is never issued."
+        );
+        assert_eq!(
+            explain("130692544"),
+            "Starts with 1: issued in Nova Scotia, New Brunswick, Prince Edward Island or \
+             Newfoundland and Labrador; Luhn check passes."
+        );
+        assert_eq!(
+            explain("446454282"),
+            "Starts with 4: issued in Ontario, or issued to military forces abroad; Luhn check \
+             passes."
+        );
+        assert_eq!(
+            explain("946454287"),
+            "Starts with 9: issued to a temporary resident; Luhn check passes."
+        );
+    }
+}
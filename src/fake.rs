@@ -0,0 +1,59 @@
+//! Support for generating test data with `fake`.
+
+use crate::{BusinessNumber, SINType, SIN, SINS_PER_FIRST_DIGIT, VALID_SINS};
+use ::fake::{Dummy, Faker, Rng};
+
+impl Dummy<Faker> for SIN {
+    /// Generates a fake SIN, which can be any SIN with a valid checksum, including placeholders
+    /// and SINs that were never issued.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// use fake::{Fake, Faker};
+    /// let sin: SIN = Faker.fake();
+    /// assert!(SIN::parse(sin.digits_string()).is_ok());
+    /// ```
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+        SIN::from_body_number(rng.random_range(0..VALID_SINS))
+    }
+}
+
+impl Dummy<Faker> for BusinessNumber {
+    /// Picks a business number with a valid checksum that starts with one of the digits that
+    /// business numbers are issued with (see [`SINType::BusinessNumber`]).
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::BusinessNumber;
+    /// use fake::{Fake, Faker};
+    /// let bn: BusinessNumber = Faker.fake();
+    /// assert!(BusinessNumber::parse(bn.to_string()).is_ok());
+    /// ```
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+        let first_digits = SINType::BusinessNumber.possible_first_digits();
+        let candidates = first_digits.len() as u32 * SINS_PER_FIRST_DIGIT;
+        let sin = SIN::from_first_digit_index(&first_digits, rng.random_range(0..candidates));
+        BusinessNumber::parse(sin.digits_string()).expect("SINs are valid business numbers")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::fake::{
+        rand::{rngs::StdRng, SeedableRng},
+        Fake,
+    };
+
+    #[test]
+    fn fake_values_are_valid() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let sin: SIN = Faker.fake_with_rng(&mut rng);
+            assert_eq!(SIN::parse(sin.digits_string()), Ok(sin));
+            let bn: BusinessNumber = Faker.fake_with_rng(&mut rng);
+            assert!(matches!(bn.digits()[0], 7 | 8));
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Formatting SINs into fixed-capacity strings, for targets without an allocator.
+
+use crate::SIN;
+
+impl SIN {
+    /// Formats the SIN into a `heapless::String`, with dashes if `dashed` is true. This doesn't
+    /// allocate.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// assert_eq!(sin.to_heapless_string(true), "046-454-286");
+    /// assert_eq!(sin.to_heapless_string(false), "046454286");
+    /// ```
+    #[cfg(feature = "heapless")]
+    pub fn to_heapless_string(&self, dashed: bool) -> ::heapless::String<11> {
+        let mut buf = [0; 11];
+        let mut s = ::heapless::String::new();
+        s.push_str(self.format_into_buffer(&mut buf, dashed))
+            .expect("SINs are at most 11 characters");
+        s
+    }
+    /// Formats the SIN into an `arrayvec::ArrayString`, with dashes if `dashed` is true. This
+    /// doesn't allocate.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// assert_eq!(sin.to_array_string(true).as_str(), "046-454-286");
+    /// assert_eq!(sin.to_array_string(false).len(), 9);
+    /// ```
+    #[cfg(feature = "arrayvec")]
+    pub fn to_array_string(&self, dashed: bool) -> ::arrayvec::ArrayString<11> {
+        let mut buf = [0; 11];
+        ::arrayvec::ArrayString::from(self.format_into_buffer(&mut buf, dashed))
+            .expect("SINs are at most 11 characters")
+    }
+}
@@ -0,0 +1,197 @@
+//! Formatting SINs in different styles.
+
+use crate::SIN;
+use core::fmt;
+
+/// Options for [`SIN::format_with`], which controls how a SIN is turned into a string. The default
+/// options format the SIN in three groups of three digits, separated by dashes, with no masking.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{FormatOptions, SIN};
+/// let sin = SIN::parse("046454286".to_string()).unwrap();
+/// assert_eq!(sin.format_with(&FormatOptions::new()), "046-454-286");
+/// assert_eq!(
+///     sin.format_with(&FormatOptions::new().with_separator(' ')),
+///     "046 454 286",
+/// );
+/// assert_eq!(
+///     sin.format_with(&FormatOptions::new().with_mask('*', 3)),
+///     "***-***-286",
+/// );
+/// assert_eq!(
+///     sin.format_with(&FormatOptions::new().without_separator()),
+///     "046454286",
+/// );
+/// assert_eq!(
+///     sin.format_with(&FormatOptions::new().with_groups(&[4, 5]).with_separator('/')),
+///     "0464/54286",
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    separator: Option<char>,
+    /// The positions where groups end, as bits, so the options don't need to allocate.
+    group_ends: u16,
+    mask: Option<(char, usize)>,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            separator: Some('-'),
+            group_ends: 1 << 3 | 1 << 6,
+            mask: None,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Creates options that format the SIN like `046-454-286`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets the character put between groups of digits.
+    pub fn with_separator(mut self, separator: char) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+    /// Puts nothing between groups of digits.
+    pub fn without_separator(mut self) -> Self {
+        self.separator = None;
+        self
+    }
+    /// Sets the sizes of the groups of digits. Any digits that aren't part of a group are put in
+    /// a final group, and empty groups are ignored.
+    pub fn with_groups(mut self, groups: &[usize]) -> Self {
+        self.group_ends = 0;
+        let mut end = 0;
+        for size in groups {
+            end += size;
+            if (1..9).contains(&end) {
+                self.group_ends |= 1 << end;
+            }
+        }
+        self
+    }
+    /// Replaces every digit except the last `visible` digits with `mask`.
+    pub fn with_mask(mut self, mask: char, visible: usize) -> Self {
+        self.mask = Some((mask, visible));
+        self
+    }
+    /// The options for [`SIN::masked`], which are also used when logging with `defmt`.
+    #[cfg(any(feature = "std", feature = "defmt"))]
+    pub(crate) fn masked() -> Self {
+        Self::new().with_mask('*', 3)
+    }
+}
+
+impl SIN {
+    /// The SIN with every digit except the last 3 replaced with `*`, like `***-***-286`. This is
+    /// how SINs are shown everywhere this crate hides them, such as in logs.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// assert_eq!(sin.masked(), "***-***-286");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn masked(&self) -> String {
+        self.format_with(&FormatOptions::masked())
+    }
+    /// Formats the SIN according to `options`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{FormatOptions, SIN};
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// let options = FormatOptions::new().with_separator('.').with_mask('X', 4);
+    /// assert_eq!(sin.format_with(&options), "XXX.XX4.286");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        let mut s = String::with_capacity(11);
+        self.write_with(options, &mut s)
+            .expect("writing to a String can't fail");
+        s
+    }
+    /// Writes the SIN to `w`, with dashes if `dashed` is true. This doesn't allocate, so it's
+    /// useful for logging in high-throughput or embedded contexts.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// use std::fmt::Write;
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// let mut log = String::from("SIN: ");
+    /// sin.format_into(&mut log, true).unwrap();
+    /// assert_eq!(log, "SIN: 046-454-286");
+    /// ```
+    pub fn format_into<W: fmt::Write>(&self, w: &mut W, dashed: bool) -> fmt::Result {
+        let mut buf = [0; 11];
+        w.write_str(self.format_into_buffer(&mut buf, dashed))
+    }
+    /// Writes the SIN into `buf`, with dashes if `dashed` is true, and returns the part of the
+    /// buffer that was used as a string. This doesn't allocate.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// let mut buf = [0; 11];
+    /// assert_eq!(sin.format_into_buffer(&mut buf, false), "046454286");
+    /// assert_eq!(sin.format_into_buffer(&mut buf, true), "046-454-286");
+    /// ```
+    pub fn format_into_buffer<'a>(&self, buf: &'a mut [u8; 11], dashed: bool) -> &'a str {
+        let mut len = 0;
+        for (idx, digit) in self.digits().iter().enumerate() {
+            if dashed && (idx == 3 || idx == 6) {
+                buf[len] = b'-';
+                len += 1;
+            }
+            buf[len] = b'0' + digit;
+            len += 1;
+        }
+        core::str::from_utf8(&buf[..len]).expect("SINs are ASCII")
+    }
+    /// Writes the SIN according to `options`.
+    #[cfg(any(feature = "std", feature = "defmt"))]
+    pub(crate) fn write_with<W: fmt::Write>(
+        &self,
+        options: &FormatOptions,
+        w: &mut W,
+    ) -> fmt::Result {
+        for (idx, digit) in self.digits().iter().enumerate() {
+            if options.group_ends & 1 << idx != 0 {
+                if let Some(separator) = options.separator {
+                    w.write_char(separator)?;
+                }
+            }
+            match options.mask {
+                Some((mask, visible)) if visible < 9 - idx => w.write_char(mask)?,
+                _ => w.write_char((b'0' + digit) as char)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn groups() {
+        let sin = SIN::parse("046454286".to_string()).unwrap();
+        let options = FormatOptions::new().with_groups(&[]);
+        assert_eq!(sin.format_with(&options), "046454286");
+        let options = FormatOptions::new().with_groups(&[0, 2, 0, 9]);
+        assert_eq!(sin.format_with(&options), "04-6454286");
+        let options = FormatOptions::new().with_mask('#', 0);
+        assert_eq!(sin.format_with(&options), "###-###-###");
+        let options = FormatOptions::new().with_mask('#', 12);
+        assert_eq!(sin.format_with(&options), "046-454-286");
+    }
+}
@@ -0,0 +1,163 @@
+//! Generating SINs for test data, without depending on a random number generator crate.
+
+use crate::{SINSet, SINType, SIN, SINS_PER_FIRST_DIGIT};
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+};
+
+/// How many random SINs are tried before falling back to a linear search when the exclusion set
+/// keeps matching.
+const MAX_RANDOM_ATTEMPTS: usize = 16;
+
+/// Generates random valid SINs, such as for filling a staging environment with synthetic data.
+/// SINs are picked uniformly from every SIN with a valid checksum, and this is also an infinite
+/// iterator of SINs.
+///
+/// This isn't cryptographically secure, so it shouldn't be used for anything that needs SINs to
+/// be unpredictable. Use [`SINGenerator::from_seed`] to get the same SINs every time.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{SINGenerator, SINSet, SIN};
+/// let production: SINSet = ["046454286", "130692544"]
+///     .iter()
+///     .map(|s| SIN::parse(s.to_string()).unwrap())
+///     .collect();
+/// let synthetic: Vec<SIN> = SINGenerator::new().excluding(&production).take(100).collect();
+/// assert!(synthetic.iter().all(|sin| !production.contains(sin)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SINGenerator<'a> {
+    state: u64,
+    excluded: Option<&'a SINSet>,
+    first_digits: Vec<u8>,
+}
+
+impl Default for SINGenerator<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> SINGenerator<'a> {
+    /// Creates a generator with a random seed.
+    pub fn new() -> Self {
+        Self::from_seed(RandomState::new().build_hasher().finish())
+    }
+    /// Creates a generator that always generates the same SINs for the same seed, on every
+    /// platform and in every version of this crate, so test fixtures can be generated instead of
+    /// checked in.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{SINGenerator, SIN};
+    /// let first: Vec<SIN> = SINGenerator::from_seed(42).take(10).collect();
+    /// let second: Vec<SIN> = SINGenerator::from_seed(42).take(10).collect();
+    /// assert_eq!(first, second);
+    /// ```
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            state: seed,
+            excluded: None,
+            first_digits: (0..=9).collect(),
+        }
+    }
+    /// Only generates SINs that could be of type `ty`, replacing any type given before. Since the
+    /// type only depends on the first digit, the SINs may also be of other types (see
+    /// [`SINType::possible_first_digits`]).
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{SINGenerator, SINType};
+    /// let generator = SINGenerator::from_seed(7).with_type(SINType::Quebec);
+    /// assert!(generator.take(100).all(|sin| sin.types().contains(&SINType::Quebec)));
+    /// ```
+    pub fn with_type(mut self, ty: SINType) -> Self {
+        self.first_digits = ty.possible_first_digits();
+        self
+    }
+    /// Never generates a SIN that is in `excluded`, such as the SINs in a production extract.
+    /// Collisions are regenerated, and if the set is so dense that random picks keep colliding,
+    /// the generator moves on to the next SIN that isn't excluded.
+    ///
+    /// Generating panics if every SIN that can be generated is excluded.
+    pub fn excluding(mut self, excluded: &'a SINSet) -> Self {
+        self.excluded = Some(excluded);
+        self
+    }
+    /// The next number from the SplitMix64 generator.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+    /// The number of SINs that can be generated, ignoring the exclusion set.
+    fn candidates(&self) -> u32 {
+        self.first_digits.len() as u32 * SINS_PER_FIRST_DIGIT
+    }
+    /// The SIN at `idx` in the SINs that can be generated, ignoring the exclusion set.
+    fn candidate(&self, idx: u32) -> SIN {
+        SIN::from_first_digit_index(&self.first_digits, idx)
+    }
+    fn is_excluded(&self, sin: &SIN) -> bool {
+        self.excluded.is_some_and(|excluded| excluded.contains(sin))
+    }
+    /// Generates a SIN.
+    pub fn generate(&mut self) -> SIN {
+        let candidates = self.candidates();
+        // the bias from the modulo is less than one in 10^11
+        let mut idx = (self.next_u64() % u64::from(candidates)) as u32;
+        for _ in 0..MAX_RANDOM_ATTEMPTS {
+            let sin = self.candidate(idx);
+            if !self.is_excluded(&sin) {
+                return sin;
+            }
+            idx = (self.next_u64() % u64::from(candidates)) as u32;
+        }
+        for _ in 0..candidates {
+            let sin = self.candidate(idx);
+            if !self.is_excluded(&sin) {
+                return sin;
+            }
+            idx = (idx + 1) % candidates;
+        }
+        panic!("every SIN that can be generated is excluded");
+    }
+}
+
+impl Iterator for SINGenerator<'_> {
+    type Item = SIN;
+
+    fn next(&mut self) -> Option<SIN> {
+        Some(self.generate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regenerates_collisions() {
+        let excluded: SINSet = SINGenerator::from_seed(0).take(5).collect();
+        let generated: Vec<SIN> = SINGenerator::from_seed(0)
+            .excluding(&excluded)
+            .take(5)
+            .collect();
+        assert_eq!(excluded.len(), 5);
+        assert!(generated.iter().all(|sin| !excluded.contains(sin)));
+    }
+
+    #[test]
+    fn stable_across_runs() {
+        let sins: Vec<String> = SINGenerator::from_seed(0)
+            .with_type(SINType::TemporaryResident)
+            .take(3)
+            .map(|sin| sin.digits_string())
+            .collect();
+        assert_eq!(sins, ["986075356", "943557009", "915456792"]);
+    }
+}
@@ -0,0 +1,40 @@
+//! A wrapper for SINs that can't be printed or serialized by accident.
+
+use crate::SIN;
+
+/// A SIN that can't be formatted, debug-printed or serialized. The SIN can only be used by
+/// calling [`GuardedSIN::reveal`], so it can't end up in logs or API responses by accident.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{GuardedSIN, SIN};
+/// let guarded = GuardedSIN::new(SIN::parse("046454286".to_string()).unwrap());
+/// assert_eq!(guarded.reveal().digits_string(), "046454286");
+/// ```
+///
+/// Printing the SIN without revealing it doesn't compile:
+/// ```compile_fail
+/// use canada_sin::{GuardedSIN, SIN};
+/// let guarded = GuardedSIN::new(SIN::parse("046454286".to_string()).unwrap());
+/// println!("{:?}", guarded);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct GuardedSIN(SIN);
+
+impl GuardedSIN {
+    /// Guards a SIN.
+    pub fn new(sin: SIN) -> Self {
+        Self(sin)
+    }
+    /// Returns the guarded SIN. Calls to this are easy to search for when auditing where SINs
+    /// are used.
+    pub fn reveal(&self) -> SIN {
+        self.0
+    }
+}
+
+impl From<SIN> for GuardedSIN {
+    fn from(sin: SIN) -> Self {
+        Self::new(sin)
+    }
+}
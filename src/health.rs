@@ -0,0 +1,191 @@
+//! Provincial health numbers. Each province issues its own health numbers, with its own format,
+//! and some provinces use check digits. [`HealthNumber`] parses a health number from any
+//! supported province.
+//!
+//! ## Examples
+//! ```
+//! use canada_sin::{health::HealthNumber, SINType};
+//! let number = HealthNumber::parse("1234-56789".to_string(), SINType::Manitoba).unwrap();
+//! assert_eq!(number.province(), SINType::Manitoba);
+//! assert_eq!(number.to_string(), "123456789");
+//! ```
+
+use crate::{SINType, SIN};
+use std::{error::Error, fmt};
+
+mod british_columbia;
+mod ontario;
+mod quebec;
+
+pub use british_columbia::BCPHN;
+pub use ontario::OHIPNumber;
+pub use quebec::{RAMQNumber, Sex};
+
+/// An error resulting from parsing a health number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HealthNumberParseError {
+    /// The health number is longer than the province's format allows.
+    TooLong,
+    /// The health number is shorter than the province's format allows.
+    TooShort,
+    /// The health number has an invalid check digit.
+    InvalidChecksum,
+    /// The health number doesn't match the province's format.
+    InvalidFormat,
+    /// Health numbers from this province (or from something that isn't a province) aren't
+    /// supported.
+    UnsupportedProvince,
+}
+
+impl fmt::Display for HealthNumberParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::TooLong => "health number is too long",
+            Self::TooShort => "health number is too short",
+            Self::InvalidChecksum => "health number has an invalid check digit",
+            Self::InvalidFormat => "health number has an invalid format",
+            Self::UnsupportedProvince => "health numbers from this province are not supported",
+        })
+    }
+}
+
+impl Error for HealthNumberParseError {}
+
+/// A health number from one of the provinces or territories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HealthNumber {
+    /// A health number from a province whose health numbers are a fixed number of digits without
+    /// a check digit.
+    Digits {
+        /// The province that issued the health number.
+        province: SINType,
+        /// The digits of the health number.
+        digits: Vec<u8>,
+    },
+    /// An Ontario health number.
+    Ontario(OHIPNumber),
+    /// A British Columbia Personal Health Number.
+    BritishColumbia(BCPHN),
+    /// A Québec health insurance number.
+    Quebec(RAMQNumber),
+}
+
+/// The number of digits in health numbers from provinces that only use digits, with no check
+/// digit.
+fn digits_only_length(province: SINType) -> Option<usize> {
+    use SINType::*;
+    match province {
+        Alberta | Manitoba | NewBrunswick | Saskatchewan | Yukon => Some(9),
+        NovaScotia => Some(10),
+        PrinceEdwardIsland => Some(8),
+        NewfoundlandLabrador => Some(12),
+        _ => None,
+    }
+}
+
+/// Checks that `digits` has exactly `len` digits.
+fn check_length(digits: &[u8], len: usize) -> Result<(), HealthNumberParseError> {
+    match digits.len() {
+        n if n < len => Err(HealthNumberParseError::TooShort),
+        n if n > len => Err(HealthNumberParseError::TooLong),
+        _ => Ok(()),
+    }
+}
+
+impl HealthNumber {
+    /// Parses a health number issued by `province`. Spaces and dashes are ignored.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{health::{HealthNumber, HealthNumberParseError}, SINType};
+    /// assert!(HealthNumber::parse("1234 5678".to_string(), SINType::PrinceEdwardIsland).is_ok());
+    /// assert_eq!(
+    ///     HealthNumber::parse("1234 5678".to_string(), SINType::NovaScotia),
+    ///     Err(HealthNumberParseError::TooShort),
+    /// );
+    /// assert_eq!(
+    ///     HealthNumber::parse("1234 5678".to_string(), SINType::BusinessNumber),
+    ///     Err(HealthNumberParseError::UnsupportedProvince),
+    /// );
+    /// ```
+    pub fn parse(s: String, province: SINType) -> Result<Self, HealthNumberParseError> {
+        match province {
+            SINType::Ontario => return OHIPNumber::parse(s).map(Self::Ontario),
+            SINType::BritishColumbia => return BCPHN::parse(s).map(Self::BritishColumbia),
+            SINType::Quebec => return RAMQNumber::parse(s).map(Self::Quebec),
+            _ => {}
+        }
+        let s: String = s.chars().filter(|&c| c != ' ' && c != '-').collect();
+        match digits_only_length(province) {
+            Some(len) => {
+                if !s.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(HealthNumberParseError::InvalidFormat);
+                }
+                let digits = SIN::extract_digits(&s);
+                check_length(&digits, len)?;
+                Ok(Self::Digits { province, digits })
+            }
+            None => Err(HealthNumberParseError::UnsupportedProvince),
+        }
+    }
+    /// The province that issued the health number.
+    pub fn province(&self) -> SINType {
+        match self {
+            Self::Digits { province, .. } => *province,
+            Self::Ontario(_) => SINType::Ontario,
+            Self::BritishColumbia(_) => SINType::BritishColumbia,
+            Self::Quebec(_) => SINType::Quebec,
+        }
+    }
+}
+
+impl fmt::Display for HealthNumber {
+    /// Formats the health number the way the issuing province does.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Digits { digits, .. } => {
+                for digit in digits {
+                    write!(f, "{}", digit)?;
+                }
+                Ok(())
+            }
+            Self::Ontario(number) => write!(f, "{}", number),
+            Self::BritishColumbia(number) => write!(f, "{}", number),
+            Self::Quebec(number) => write!(f, "{}", number),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digits_only() {
+        let number =
+            HealthNumber::parse("1234 5678 9012".to_string(), SINType::NewfoundlandLabrador)
+                .unwrap();
+        assert_eq!(number.to_string(), "123456789012");
+        assert_eq!(
+            HealthNumber::parse("12345678A".to_string(), SINType::Alberta),
+            Err(HealthNumberParseError::InvalidFormat)
+        );
+        assert_eq!(
+            HealthNumber::parse("1234567890".to_string(), SINType::Alberta),
+            Err(HealthNumberParseError::TooLong)
+        );
+    }
+
+    #[test]
+    fn dispatches_by_province() {
+        let number = HealthNumber::parse("1234567897".to_string(), SINType::Ontario).unwrap();
+        assert_eq!(number.province(), SINType::Ontario);
+        assert_eq!(number.to_string(), "1234-567-897");
+        assert_eq!(
+            HealthNumber::parse("1234567898".to_string(), SINType::Ontario),
+            Err(HealthNumberParseError::InvalidChecksum)
+        );
+    }
+}
@@ -0,0 +1,143 @@
+//! British Columbia health numbers.
+
+use super::{check_length, HealthNumberParseError};
+use crate::{mod11, SIN};
+use std::{convert::TryInto, fmt};
+
+/// The weights for the 2nd to 9th digits.
+const WEIGHTS: [u32; 8] = [2, 4, 8, 5, 10, 9, 7, 3];
+
+/// A British Columbia Personal Health Number: 10 digits starting with 9, where the last digit is a
+/// weighted modulus 11 check digit.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::health::BCPHN;
+/// let number = BCPHN::parse("9698 658 215".to_string()).unwrap();
+/// assert_eq!(number.to_string(), "9698 658 215");
+/// assert!(BCPHN::parse("9698 658 216".to_string()).is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct BCPHN {
+    digits: [u8; 10],
+}
+
+/// The check digit for the 2nd to 9th digits, if there is one. There isn't one if the remainder
+/// is 0 or 1, since the check digit would be 11 or 10.
+fn check_digit(body: &[u8]) -> Option<u8> {
+    match mod11::remainder(body, &WEIGHTS) {
+        0 | 1 => None,
+        remainder => Some((11 - remainder) as u8),
+    }
+}
+
+impl BCPHN {
+    /// Parses a Personal Health Number. Spaces and dashes are ignored.
+    pub fn parse(s: String) -> Result<Self, HealthNumberParseError> {
+        if !s
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == ' ' || c == '-')
+        {
+            return Err(HealthNumberParseError::InvalidFormat);
+        }
+        let digits = SIN::extract_digits(&s);
+        check_length(&digits, 10)?;
+        if digits[0] != 9 {
+            return Err(HealthNumberParseError::InvalidFormat);
+        }
+        if check_digit(&digits[1..9]) != Some(digits[9]) {
+            return Err(HealthNumberParseError::InvalidChecksum);
+        }
+        Ok(Self {
+            digits: digits.try_into().unwrap(),
+        })
+    }
+    /// Creates a Personal Health Number from its 2nd to 9th digits by adding the leading 9 and the
+    /// check digit. This returns `None` for the roughly 1 in 11 bodies that don't have a check
+    /// digit.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::health::BCPHN;
+    /// let number = BCPHN::from_body([6, 9, 8, 6, 5, 8, 2, 1]).unwrap();
+    /// assert_eq!(number.to_string(), "9698 658 215");
+    /// ```
+    pub fn from_body(body: [u8; 8]) -> Option<Self> {
+        if body.iter().any(|&digit| digit > 9) {
+            return None;
+        }
+        let check_digit = check_digit(&body)?;
+        let mut digits = [9; 10];
+        digits[1..9].copy_from_slice(&body);
+        digits[9] = check_digit;
+        Some(Self { digits })
+    }
+    /// Generates valid Personal Health Numbers for test data, in order, starting from
+    /// `9000 000 0xx`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::health::BCPHN;
+    /// let numbers: Vec<BCPHN> = BCPHN::test_numbers().take(3).collect();
+    /// assert_eq!(numbers.len(), 3);
+    /// assert!(numbers.iter().all(|n| BCPHN::parse(n.to_string()).is_ok()));
+    /// ```
+    pub fn test_numbers() -> impl Iterator<Item = Self> {
+        (0..100_000_000u32).filter_map(|mut number| {
+            let mut body = [0; 8];
+            for digit in body.iter_mut().rev() {
+                *digit = (number % 10) as u8;
+                number /= 10;
+            }
+            Self::from_body(body)
+        })
+    }
+    /// Returns the 10 digits of the health number.
+    pub fn digits(&self) -> [u8; 10] {
+        self.digits
+    }
+}
+
+impl fmt::Display for BCPHN {
+    /// Formats the health number like `9698 658 215`, which is how it is printed on BC Services
+    /// Cards.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, digit) in self.digits.iter().enumerate() {
+            if idx == 4 || idx == 7 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_phn() {
+        assert!(BCPHN::parse("9698658215".to_string()).is_ok());
+        assert_eq!(
+            BCPHN::parse("8698658215".to_string()),
+            Err(HealthNumberParseError::InvalidFormat)
+        );
+        assert_eq!(
+            BCPHN::parse("969865821".to_string()),
+            Err(HealthNumberParseError::TooShort)
+        );
+        assert_eq!(
+            BCPHN::parse("9698658215A".to_string()),
+            Err(HealthNumberParseError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn generated_numbers_are_valid() {
+        for number in BCPHN::test_numbers().take(100) {
+            assert_eq!(BCPHN::parse(number.to_string()), Ok(number));
+        }
+        assert_eq!(BCPHN::from_body([10, 0, 0, 0, 0, 0, 0, 0]), None);
+    }
+}
@@ -0,0 +1,101 @@
+//! Ontario health numbers.
+
+use super::{check_length, HealthNumberParseError};
+use crate::{luhn, SIN};
+use std::{convert::TryInto, fmt};
+
+/// An Ontario health number (OHIP number): 10 digits, where the last digit is a Luhn check digit,
+/// optionally followed by a version code of 2 letters that changes when a new card is issued.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::health::OHIPNumber;
+/// let number = OHIPNumber::parse("1234-567-897-ab".to_string()).unwrap();
+/// assert_eq!(number.version_code(), Some("AB"));
+/// assert_eq!(number.to_string(), "1234-567-897-AB");
+/// assert!(OHIPNumber::parse("1234-567-898".to_string()).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OHIPNumber {
+    digits: [u8; 10],
+    version_code: Option<String>,
+}
+
+impl OHIPNumber {
+    /// Parses an Ontario health number, with or without a version code. Spaces and dashes are
+    /// ignored, and the version code can be in either case.
+    pub fn parse(s: String) -> Result<Self, HealthNumberParseError> {
+        let s: String = s.chars().filter(|&c| c != ' ' && c != '-').collect();
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (digits, version_code) = s.split_at(split);
+        let digits = SIN::extract_digits(digits);
+        check_length(&digits, 10)?;
+        let version_code = match version_code.len() {
+            0 => None,
+            2 if version_code.chars().all(|c| c.is_ascii_alphabetic()) => {
+                Some(version_code.to_ascii_uppercase())
+            }
+            _ => return Err(HealthNumberParseError::InvalidFormat),
+        };
+        if !luhn::is_valid(&digits) {
+            return Err(HealthNumberParseError::InvalidChecksum);
+        }
+        Ok(Self {
+            digits: digits.try_into().unwrap(),
+            version_code,
+        })
+    }
+    /// Returns the 10 digits of the health number.
+    pub fn digits(&self) -> [u8; 10] {
+        self.digits
+    }
+    /// Returns the version code, if there is one.
+    pub fn version_code(&self) -> Option<&str> {
+        self.version_code.as_deref()
+    }
+}
+
+impl fmt::Display for OHIPNumber {
+    /// Formats the health number like `1234-567-897-AB`, or `1234-567-897` if there is no version
+    /// code.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, digit) in self.digits.iter().enumerate() {
+            if idx == 4 || idx == 7 {
+                f.write_str("-")?;
+            }
+            write!(f, "{}", digit)?;
+        }
+        if let Some(version_code) = &self.version_code {
+            write!(f, "-{}", version_code)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ohip() {
+        let number = OHIPNumber::parse("9876543217".to_string()).unwrap();
+        assert_eq!(number.version_code(), None);
+        assert_eq!(number.to_string(), "9876-543-217");
+        assert_eq!(
+            OHIPNumber::parse("9876543218".to_string()),
+            Err(HealthNumberParseError::InvalidChecksum)
+        );
+        assert_eq!(
+            OHIPNumber::parse("9876543217A".to_string()),
+            Err(HealthNumberParseError::InvalidFormat)
+        );
+        assert_eq!(
+            OHIPNumber::parse("9876543217AB1".to_string()),
+            Err(HealthNumberParseError::InvalidFormat)
+        );
+        assert_eq!(
+            OHIPNumber::parse("987654321AB".to_string()),
+            Err(HealthNumberParseError::TooShort)
+        );
+    }
+}
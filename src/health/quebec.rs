@@ -0,0 +1,210 @@
+//! Québec health numbers.
+
+use super::HealthNumberParseError;
+use crate::{names::name_letters, Date};
+use std::fmt;
+
+/// A person's sex, as encoded in some identifiers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Sex {
+    Female,
+    Male,
+}
+
+/// A Québec health insurance number (RAMQ number), like `TREJ 8503 1412`. It is made of:
+/// - the first 3 letters of the surname and the first letter of the given name
+/// - the last 2 digits of the year of birth
+/// - the month of birth, plus 50 for women
+/// - the day of birth
+/// - 2 administrative digits
+///
+/// ## Examples
+/// ```
+/// use canada_sin::health::{RAMQNumber, Sex};
+/// let number = RAMQNumber::parse("TREJ 8553 1412".to_string()).unwrap();
+/// assert_eq!(number.surname_letters(), "TRE");
+/// assert_eq!(number.given_name_letter(), 'J');
+/// assert_eq!(number.birth_year_digits(), 85);
+/// assert_eq!(number.birth_month(), 3);
+/// assert_eq!(number.birth_day(), 14);
+/// assert_eq!(number.sex(), Sex::Female);
+/// assert_eq!(number.to_string(), "TREJ 8553 1412");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RAMQNumber {
+    letters: String,
+    year: u8,
+    month: u8,
+    day: u8,
+    sex: Sex,
+    admin: u8,
+}
+
+impl RAMQNumber {
+    /// Parses a RAMQ number. Spaces are ignored, and letters can be in either case.
+    pub fn parse(s: String) -> Result<Self, HealthNumberParseError> {
+        let s: String = s.chars().filter(|&c| c != ' ').collect();
+        if !s.is_ascii() {
+            return Err(HealthNumberParseError::InvalidFormat);
+        }
+        match s.len() {
+            n if n < 12 => return Err(HealthNumberParseError::TooShort),
+            n if n > 12 => return Err(HealthNumberParseError::TooLong),
+            _ => {}
+        }
+        let (letters, digits) = s.split_at(4);
+        if !letters.chars().all(|c| c.is_ascii_alphabetic())
+            || !digits.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(HealthNumberParseError::InvalidFormat);
+        }
+        let pair = |idx: usize| digits[idx..idx + 2].parse::<u8>().unwrap();
+        let (month, sex) = match pair(2) {
+            month @ 1..=12 => (month, Sex::Male),
+            month @ 51..=62 => (month - 50, Sex::Female),
+            _ => return Err(HealthNumberParseError::InvalidFormat),
+        };
+        let (year, day) = (pair(0), pair(4));
+        // the century isn't known, so the date only has to exist in one of them
+        let year_in = |century: u16| Date::new(century + u16::from(year), month, day);
+        if year_in(1900).or_else(|| year_in(2000)).is_none() {
+            return Err(HealthNumberParseError::InvalidFormat);
+        }
+        Ok(Self {
+            letters: letters.to_ascii_uppercase(),
+            year,
+            month,
+            day,
+            sex,
+            admin: pair(6),
+        })
+    }
+    /// The first 3 letters of the surname.
+    pub fn surname_letters(&self) -> &str {
+        &self.letters[..3]
+    }
+    /// The first letter of the given name.
+    pub fn given_name_letter(&self) -> char {
+        self.letters.as_bytes()[3] as char
+    }
+    /// The last 2 digits of the year of birth. The century isn't part of the number.
+    pub fn birth_year_digits(&self) -> u8 {
+        self.year
+    }
+    /// The month of birth, from 1 to 12.
+    pub fn birth_month(&self) -> u8 {
+        self.month
+    }
+    /// The day of birth.
+    pub fn birth_day(&self) -> u8 {
+        self.day
+    }
+    /// The sex of the person.
+    pub fn sex(&self) -> Sex {
+        self.sex
+    }
+    /// Checks if the information in the number matches a person. Accents and characters that
+    /// aren't letters are ignored in names, and surnames shorter than 3 letters are padded with
+    /// `X`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{health::{RAMQNumber, Sex}, Date};
+    /// let number = RAMQNumber::parse("TREJ 8553 1412".to_string()).unwrap();
+    /// let birth_date = Date::new(1985, 3, 14).unwrap();
+    /// assert!(number.is_consistent_with("Tremblay", "Julie", birth_date, Sex::Female));
+    /// assert!(!number.is_consistent_with("Tremblay", "Julie", birth_date, Sex::Male));
+    /// assert!(!number.is_consistent_with("Gagnon", "Julie", birth_date, Sex::Female));
+    /// ```
+    pub fn is_consistent_with(
+        &self,
+        surname: &str,
+        given_name: &str,
+        birth_date: Date,
+        sex: Sex,
+    ) -> bool {
+        let surname: String = name_letters(surname).chain("XXX".chars()).take(3).collect();
+        surname == self.surname_letters()
+            && name_letters(given_name).next() == Some(self.given_name_letter())
+            && (birth_date.year() % 100) as u8 == self.year
+            && birth_date.month() == self.month
+            && birth_date.day() == self.day
+            && sex == self.sex
+    }
+}
+
+impl fmt::Display for RAMQNumber {
+    /// Formats the number like `TREJ 8553 1412`, which is how it is printed on health insurance
+    /// cards.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let month = match self.sex {
+            Sex::Male => self.month,
+            Sex::Female => self.month + 50,
+        };
+        write!(
+            f,
+            "{} {:02}{:02} {:02}{:02}",
+            self.letters, self.year, month, self.day, self.admin
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ramq() {
+        for s in [
+            "leéj12013102",
+            "lééj1201310",
+            "1EEJ 1201 3102",
+            "LEEJ 12O1 3102",
+        ] {
+            assert_eq!(
+                RAMQNumber::parse(s.to_string()),
+                Err(HealthNumberParseError::InvalidFormat)
+            );
+        }
+        let number = RAMQNumber::parse("lee j120131 02".to_string()).unwrap();
+        assert_eq!(number.to_string(), "LEEJ 1201 3102");
+        assert_eq!(number.sex(), Sex::Male);
+        assert!(number.is_consistent_with(
+            "Lée",
+            "Jean-Guy",
+            Date::new(2012, 1, 31).unwrap(),
+            Sex::Male
+        ));
+        assert_eq!(
+            RAMQNumber::parse("LEEJ 1213 3102".to_string()),
+            Err(HealthNumberParseError::InvalidFormat)
+        );
+        assert_eq!(
+            RAMQNumber::parse("LEEJ 1201 3202".to_string()),
+            Err(HealthNumberParseError::InvalidFormat)
+        );
+        assert_eq!(
+            RAMQNumber::parse("LEEJ 1201 310".to_string()),
+            Err(HealthNumberParseError::TooShort)
+        );
+    }
+
+    #[test]
+    fn birth_dates_exist() {
+        for s in ["TREJ 8502 3112", "TREJ 8552 2912", "TREJ 8504 3112"] {
+            assert_eq!(
+                RAMQNumber::parse(s.to_string()),
+                Err(HealthNumberParseError::InvalidFormat)
+            );
+        }
+        assert!(RAMQNumber::parse("TREJ 8402 2912".to_string()).is_ok());
+        // February 29, 2000
+        assert!(RAMQNumber::parse("TREJ 0052 2912".to_string()).is_ok());
+    }
+
+    #[test]
+    fn short_surnames() {
+        let number = RAMQNumber::parse("NGXA 9002 0101".to_string()).unwrap();
+        assert!(number.is_consistent_with("Ng", "Anh", Date::new(1990, 2, 1).unwrap(), Sex::Male));
+    }
+}
@@ -0,0 +1,195 @@
+//! A common interface over the identifier types in this crate.
+
+use crate::{
+    health::{OHIPNumber, RAMQNumber, BCPHN},
+    BusinessNumber, NonResidentAccountNumber, ProgramAccount, QSTNumber, TrustAccountNumber, NEQ,
+    PRI, SIN, UCI,
+};
+use std::{error::Error, fmt};
+
+/// A kind of Canadian identifier.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IdKind {
+    /// A social insurance number.
+    SIN,
+    /// A 9-digit business number.
+    BusinessNumber,
+    /// A 15-character program account number, made from a business number.
+    ProgramAccount,
+    /// A Québec enterprise number.
+    NEQ,
+    /// A federal Personal Record Identifier.
+    PRI,
+    /// An IRCC Unique Client Identifier.
+    UCI,
+    /// A Québec sales tax registration number.
+    QSTNumber,
+    /// A CRA trust account number.
+    TrustAccountNumber,
+    /// A CRA non-resident account number.
+    NonResidentAccountNumber,
+    /// An Ontario health number.
+    OHIPNumber,
+    /// A British Columbia personal health number.
+    BCPHN,
+    /// A Québec health insurance number.
+    RAMQNumber,
+}
+
+/// An identifier that can be parsed, displayed in a canonical form, and masked. This lets generic
+/// code handle any of the identifiers in this crate the same way.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{BusinessNumber, CanadianId, IdKind, SIN};
+/// fn intake<T: CanadianId>(s: &str) -> Option<(IdKind, String)> {
+///     let id = T::parse(s).ok()?;
+///     Some((id.kind(), id.masked()))
+/// }
+/// assert_eq!(intake::<SIN>("046-454-286"), Some((IdKind::SIN, "***-***-286".to_string())));
+/// assert_eq!(
+///     intake::<BusinessNumber>("123456782"),
+///     Some((IdKind::BusinessNumber, "******782".to_string())),
+/// );
+/// ```
+pub trait CanadianId: Sized + fmt::Display {
+    /// The error returned when parsing fails.
+    type Error: Error;
+    /// Parses the identifier.
+    fn parse(s: &str) -> Result<Self, Self::Error>;
+    /// Returns the kind of identifier this is.
+    fn kind(&self) -> IdKind;
+    /// Returns the identifier without any spaces or hyphens.
+    fn canonical_string(&self) -> String {
+        self.to_string()
+            .chars()
+            .filter(|&c| c != ' ' && c != '-')
+            .collect()
+    }
+    /// Returns the identifier with all but its last 3 characters hidden, for showing in logs and
+    /// user interfaces. The format depends on the type, so it shouldn't be parsed or compared
+    /// across types. By default, this is the canonical string with every character except the
+    /// last 3 replaced with `*`, but SINs keep their dashes (see [`SIN::masked`]).
+    fn masked(&self) -> String {
+        let canonical = self.canonical_string();
+        let hidden = canonical.chars().count().saturating_sub(3);
+        canonical
+            .chars()
+            .enumerate()
+            .map(|(idx, c)| if idx < hidden { '*' } else { c })
+            .collect()
+    }
+}
+
+macro_rules! impl_canadian_id {
+    ($($ty:ident => $error:ty,)*) => {
+        $(
+            impl CanadianId for $ty {
+                type Error = $error;
+
+                fn parse(s: &str) -> Result<Self, Self::Error> {
+                    $ty::parse(s.to_string())
+                }
+                fn kind(&self) -> IdKind {
+                    IdKind::$ty
+                }
+            }
+        )*
+    };
+}
+
+impl CanadianId for SIN {
+    type Error = crate::SINParseError;
+
+    fn parse(s: &str) -> Result<Self, Self::Error> {
+        SIN::parse(s)
+    }
+    fn kind(&self) -> IdKind {
+        IdKind::SIN
+    }
+    /// Returns the SIN masked like [`SIN::masked`], such as `***-***-286`, so that it's shown the
+    /// same way everywhere.
+    fn masked(&self) -> String {
+        SIN::masked(self)
+    }
+}
+
+impl_canadian_id! {
+    BusinessNumber => crate::AccountNumberParseError,
+    ProgramAccount => crate::AccountNumberParseError,
+    NEQ => crate::NEQParseError,
+    PRI => crate::PRIParseError,
+    UCI => crate::UCIParseError,
+    QSTNumber => crate::AccountNumberParseError,
+    TrustAccountNumber => crate::AccountNumberParseError,
+    NonResidentAccountNumber => crate::AccountNumberParseError,
+    OHIPNumber => crate::health::HealthNumberParseError,
+    BCPHN => crate::health::HealthNumberParseError,
+    RAMQNumber => crate::health::HealthNumberParseError,
+}
+
+/// Guesses which kinds of identifier `input` could be, based on the length, prefixes, and
+/// checksums of each kind. Many identifiers share a format, so `input` can match several kinds:
+/// every valid SIN is also a valid business number, for example. Kinds that do less validation,
+/// such as [`IdKind::UCI`], match more often.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{identify, IdKind};
+/// let kinds = identify("046 454 286");
+/// assert!(kinds.contains(&IdKind::SIN));
+/// assert!(kinds.contains(&IdKind::BusinessNumber));
+/// assert_eq!(identify("123456782RP0001"), vec![IdKind::ProgramAccount]);
+/// assert_eq!(identify("TREJ 8553 1412"), vec![IdKind::RAMQNumber]);
+/// assert!(identify("hello").is_empty());
+/// ```
+pub fn identify(input: &str) -> Vec<IdKind> {
+    fn kind_of<T: CanadianId>(input: &str) -> Option<IdKind> {
+        T::parse(input).ok().map(|id| id.kind())
+    }
+    [
+        kind_of::<SIN>,
+        kind_of::<BusinessNumber>,
+        kind_of::<ProgramAccount>,
+        kind_of::<NEQ>,
+        kind_of::<PRI>,
+        kind_of::<UCI>,
+        kind_of::<QSTNumber>,
+        kind_of::<TrustAccountNumber>,
+        kind_of::<NonResidentAccountNumber>,
+        kind_of::<OHIPNumber>,
+        kind_of::<BCPHN>,
+        kind_of::<RAMQNumber>,
+    ]
+    .iter()
+    .filter_map(|kind_of| kind_of(input))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_strings() {
+        let account = <ProgramAccount as CanadianId>::parse("123456782 RP 0001").unwrap();
+        assert_eq!(account.kind(), IdKind::ProgramAccount);
+        assert_eq!(account.canonical_string(), "123456782RP0001");
+        assert_eq!(account.masked(), "************001");
+        let phn = <BCPHN as CanadianId>::parse("9698 658 215").unwrap();
+        assert_eq!(phn.canonical_string(), "9698658215");
+        let uci = <UCI as CanadianId>::parse("12-3456-7890").unwrap();
+        assert_eq!(uci.masked(), "*******890");
+        let sin = <SIN as CanadianId>::parse("046454286").unwrap();
+        assert_eq!(CanadianId::masked(&sin), "***-***-286");
+    }
+
+    #[test]
+    fn identify_kinds() {
+        assert_eq!(identify("9698 658 215"), vec![IdKind::UCI, IdKind::BCPHN]);
+        assert_eq!(identify("1143920115"), vec![IdKind::NEQ, IdKind::UCI]);
+        assert_eq!(identify("T12345678"), vec![IdKind::TrustAccountNumber]);
+        assert!(identify("").is_empty());
+    }
+}
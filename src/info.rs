@@ -0,0 +1,76 @@
+//! Everything that can be worked out about a SIN, gathered in one place.
+
+use crate::{SINType, SIN};
+
+/// Information about a SIN, as returned by [`SIN::info`]. This is useful for reports and
+/// exports that need most of this for every record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SINInfo {
+    /// All types the SIN could be, from [`SIN::types`].
+    pub types: Vec<SINType>,
+    /// The provinces and territories the SIN could have been issued in, which are the types that
+    /// are provinces (see [`SINType::is_province`]).
+    pub provinces: Vec<SINType>,
+    /// Could the SIN belong to a person?
+    pub could_be_human: bool,
+    /// Could the SIN be a business number?
+    pub could_be_business: bool,
+    /// Is the SIN in the 900 series, which is given to temporary residents?
+    pub is_nine_series: bool,
+    /// The SIN with all but the last 3 digits hidden, like `***-***-286`.
+    pub masked: String,
+    /// The SIN without separators, like `046454286`.
+    pub digits: String,
+    /// The SIN with dashes, like `046-454-286`.
+    pub dashed: String,
+}
+
+impl SIN {
+    /// Gathers information about the SIN into one struct, instead of calling several methods.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{SINType, SIN};
+    /// let info = SIN::parse("734323843".to_string()).unwrap().info();
+    /// assert_eq!(info.provinces, vec![SINType::BritishColumbia, SINType::Yukon]);
+    /// assert!(info.could_be_human && info.could_be_business);
+    /// assert!(!info.is_nine_series);
+    /// assert_eq!(info.masked, "***-***-843");
+    /// assert_eq!(info.dashed, "734-323-843");
+    /// ```
+    pub fn info(&self) -> SINInfo {
+        let types = self.types();
+        SINInfo {
+            provinces: types
+                .iter()
+                .copied()
+                .filter(|ty| ty.is_province())
+                .collect(),
+            could_be_human: types.iter().any(|ty| ty.is_human()),
+            could_be_business: types.contains(&SINType::BusinessNumber),
+            is_nine_series: self.is_nine_series(),
+            masked: self.masked(),
+            digits: self.digits_string(),
+            dashed: self.digits_dashed_string(),
+            types,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn business_and_temporary() {
+        let info = SIN::parse("800000002".to_string()).unwrap().info();
+        assert!(!info.could_be_human && info.could_be_business);
+        assert!(info.provinces.is_empty());
+        let info = SIN::parse("946454287".to_string()).unwrap().info();
+        assert!(info.is_nine_series && info.could_be_human);
+        assert_eq!(info.types, vec![SINType::TemporaryResident]);
+        assert_eq!(info.digits, "946454287");
+    }
+}
@@ -0,0 +1,200 @@
+//! Validation of SINs and business numbers in JSON Lines (newline-delimited JSON) streams.
+
+use crate::{BusinessNumber, Locale, SIN};
+use serde_json::{Map, Value};
+use std::{
+    error::Error,
+    fmt,
+    io::{self, BufRead, Write},
+};
+
+/// An error resulting from validating a JSON Lines stream.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum JsonLinesError {
+    /// A line isn't a JSON object. `line` starts at 1.
+    InvalidRecord { line: usize },
+    /// The stream couldn't be read or written.
+    Io(io::Error),
+}
+
+impl fmt::Display for JsonLinesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRecord { line } => write!(f, "line {} isn't a JSON object", line),
+            Self::Io(err) => write!(f, "I/O error: {}", err),
+        }
+    }
+}
+
+impl Error for JsonLinesError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidRecord { .. } => None,
+            Self::Io(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for JsonLinesError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Validates the SIN and business number fields of JSON Lines records.
+///
+/// Records where every configured field is valid are enriched and written to the accepted
+/// stream: each field is replaced with its 9 digits, and SIN fields get a `<field>_types` field
+/// listing the types the SIN could be (see [`SIN::types`]). Other records are written unchanged
+/// to the rejected stream, with an `_errors` object mapping each invalid field to the reason.
+/// Blank lines are skipped.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::jsonl::JsonLinesValidator;
+/// let input = r#"{"sin":"046-454-286"}
+/// {"sin":"046-454-287"}
+/// "#;
+/// let (mut accepted, mut rejected) = (Vec::new(), Vec::new());
+/// JsonLinesValidator::new()
+///     .with_sin_field("sin")
+///     .validate(input.as_bytes(), &mut accepted, &mut rejected)
+///     .unwrap();
+/// assert_eq!(
+///     String::from_utf8(accepted).unwrap(),
+///     "{\"sin\":\"046454286\",\"sin_types\":[\"CRA-assigned\"]}\n",
+/// );
+/// assert_eq!(
+///     String::from_utf8(rejected).unwrap(),
+///     "{\"_errors\":{\"sin\":\"SIN has an invalid checksum\"},\"sin\":\"046-454-287\"}\n",
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct JsonLinesValidator {
+    sin_fields: Vec<String>,
+    business_number_fields: Vec<String>,
+}
+
+impl JsonLinesValidator {
+    /// Creates a validator that doesn't check any fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Validates the field `name` as a SIN.
+    pub fn with_sin_field(mut self, name: &str) -> Self {
+        self.sin_fields.push(name.to_string());
+        self
+    }
+    /// Validates the field `name` as a business number.
+    pub fn with_business_number_field(mut self, name: &str) -> Self {
+        self.business_number_fields.push(name.to_string());
+        self
+    }
+    /// Reads records from `reader`, writing valid records to `accepted` and invalid records to
+    /// `rejected`. This fails if a line isn't a JSON object.
+    pub fn validate<R: BufRead, A: Write, J: Write>(
+        &self,
+        reader: R,
+        mut accepted: A,
+        mut rejected: J,
+    ) -> Result<(), JsonLinesError> {
+        for (idx, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut record = match serde_json::from_str(&line) {
+                Ok(Value::Object(record)) => record,
+                _ => return Err(JsonLinesError::InvalidRecord { line: idx + 1 }),
+            };
+            let errors = self.check(&mut record);
+            let (out, record): (&mut dyn Write, _) = if errors.is_empty() {
+                (&mut accepted, Value::Object(record))
+            } else {
+                let mut original: Map<String, Value> =
+                    serde_json::from_str(&line).expect("line was already parsed");
+                original.insert("_errors".to_string(), Value::Object(errors));
+                (&mut rejected, Value::Object(original))
+            };
+            writeln!(out, "{}", record)?;
+        }
+        accepted.flush()?;
+        rejected.flush()?;
+        Ok(())
+    }
+    /// Validates and enriches the fields of `record`, returning the errors for invalid fields.
+    fn check(&self, record: &mut Map<String, Value>) -> Map<String, Value> {
+        let mut errors = Map::new();
+        for field in &self.sin_fields {
+            match Self::field_str(record, field).map(|s| SIN::parse(s).map_err(|e| e.to_string())) {
+                Ok(Ok(sin)) => {
+                    let types = sin
+                        .types()
+                        .iter()
+                        .map(|ty| Value::from(ty.name(Locale::English)))
+                        .collect();
+                    record.insert(field.clone(), Value::from(sin.digits_string()));
+                    record.insert(format!("{}_types", field), Value::Array(types));
+                }
+                Ok(Err(err)) | Err(err) => {
+                    errors.insert(field.clone(), Value::from(err));
+                }
+            }
+        }
+        for field in &self.business_number_fields {
+            match Self::field_str(record, field)
+                .map(|s| BusinessNumber::parse(s).map_err(|e| e.to_string()))
+            {
+                Ok(Ok(bn)) => {
+                    record.insert(field.clone(), Value::from(bn.to_string()));
+                }
+                Ok(Err(err)) | Err(err) => {
+                    errors.insert(field.clone(), Value::from(err));
+                }
+            }
+        }
+        errors
+    }
+    /// Gets the string value of a field.
+    fn field_str(record: &Map<String, Value>, field: &str) -> Result<String, String> {
+        match record.get(field) {
+            Some(Value::String(s)) => Ok(s.clone()),
+            Some(_) => Err("field isn't a string".to_string()),
+            None => Err("field is missing".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn business_numbers_and_missing_fields() {
+        let input = "{\"bn\":\"123 456 782\"}\n\n{\"bn\":1}\n{}\n";
+        let (mut accepted, mut rejected) = (Vec::new(), Vec::new());
+        JsonLinesValidator::new()
+            .with_business_number_field("bn")
+            .validate(input.as_bytes(), &mut accepted, &mut rejected)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(accepted).unwrap(),
+            "{\"bn\":\"123456782\"}\n"
+        );
+        assert_eq!(
+            String::from_utf8(rejected).unwrap(),
+            "{\"_errors\":{\"bn\":\"field isn't a string\"},\"bn\":1}\n\
+             {\"_errors\":{\"bn\":\"field is missing\"}}\n"
+        );
+    }
+
+    #[test]
+    fn invalid_record() {
+        let (mut accepted, mut rejected) = (Vec::new(), Vec::new());
+        assert!(matches!(
+            JsonLinesValidator::new().validate("{}\n[]\n".as_bytes(), &mut accepted, &mut rejected),
+            Err(JsonLinesError::InvalidRecord { line: 2 })
+        ));
+    }
+}
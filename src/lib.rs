@@ -1,6 +1,187 @@
-//! A library for parsing Canadian social insurance numbers and business numbers.
+//! A library for parsing Canadian social insurance numbers and business numbers, along with some
+//! other Canadian identifiers.
+//!
+//! ## `no_std`
+//! The `std` feature is enabled by default. Without it, the crate is `no_std` and doesn't need an
+//! allocator, so it can be used in firmware. Only parsing, validating and formatting [`SIN`]s is
+//! available then, along with the `heapless`, `arrayvec` and `defmt` features.
 
-use std::{convert::TryInto, fmt};
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+// `is_multiple_of` needs Rust 1.87, so checksums use `%` instead.
+#![allow(clippy::manual_is_multiple_of)]
+
+#[cfg(feature = "std")]
+use core::convert::{TryFrom, TryInto};
+use core::{fmt, num::NonZeroU32, str::FromStr};
+#[cfg(feature = "std")]
+use std::{borrow::Cow, error::Error};
+
+#[cfg(feature = "quickcheck")]
+mod arbitrary;
+#[cfg(feature = "barcode")]
+mod barcode;
+#[cfg(feature = "std")]
+mod builder;
+#[cfg(feature = "std")]
+mod business_number;
+#[cfg(feature = "std")]
+mod check;
+#[cfg(feature = "std")]
+mod cra;
+#[cfg(feature = "csv")]
+pub mod csv;
+mod date;
+#[cfg(all(feature = "defmt", not(feature = "std")))]
+pub mod defmt;
+#[cfg(feature = "std")]
+mod diagnose;
+#[cfg(feature = "digest")]
+mod digest;
+#[cfg(feature = "std")]
+pub mod drivers_licence;
+#[cfg(feature = "std")]
+mod explain;
+#[cfg(feature = "fake")]
+mod fake;
+#[cfg(any(feature = "heapless", feature = "arrayvec"))]
+mod fixed_string;
+mod format;
+#[cfg(feature = "std")]
+mod generator;
+mod guarded;
+#[cfg(feature = "std")]
+pub mod health;
+#[cfg(feature = "std")]
+mod id;
+#[cfg(feature = "std")]
+mod info;
+#[cfg(feature = "jsonl")]
+pub mod jsonl;
+mod luhn;
+#[cfg(feature = "std")]
+mod matching;
+#[cfg(feature = "std")]
+mod mod11;
+#[cfg(feature = "std")]
+mod names;
+#[cfg(feature = "std")]
+mod neq;
+#[cfg(feature = "derive")]
+mod newtype;
+#[cfg(feature = "std")]
+mod nine_series;
+#[cfg(feature = "std")]
+mod ocr;
+#[cfg(feature = "std")]
+mod parse_options;
+#[cfg(feature = "std")]
+mod partial;
+mod plausibility;
+#[cfg(feature = "std")]
+mod policy;
+#[cfg(feature = "std")]
+mod pri;
+#[cfg(feature = "std")]
+mod pseudonymize;
+#[cfg(feature = "std")]
+mod qst;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "std")]
+mod range;
+#[cfg(feature = "std")]
+mod scan;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "serde_with")]
+mod serde_as;
+#[cfg(feature = "std")]
+mod set;
+#[cfg(feature = "std")]
+mod siphash;
+#[cfg(feature = "std")]
+mod spoken;
+#[cfg(feature = "tracing")]
+pub mod tracing;
+mod typed;
+mod types;
+#[cfg(feature = "std")]
+mod uci;
+mod unicode;
+#[cfg(feature = "clap")]
+mod value_parser;
+#[cfg(feature = "std")]
+mod verified;
+#[cfg(feature = "std")]
+mod verify;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+#[cfg(feature = "barcode")]
+pub use barcode::{BarcodeContent, ExposesFullSIN};
+#[cfg(feature = "std")]
+pub use builder::SINBuilder;
+#[cfg(feature = "std")]
+pub use business_number::{BusinessNumber, ProgramAccount, ProgramIdentifier, ReferenceNumber};
+#[cfg(feature = "derive")]
+pub use canada_sin_derive::SINNewtype;
+#[cfg(feature = "std")]
+pub use check::SINProblem;
+#[cfg(feature = "std")]
+pub use cra::{AccountNumberParseError, NonResidentAccountNumber, TrustAccountNumber};
+pub use date::Date;
+#[cfg(feature = "std")]
+pub use diagnose::{ChecksumDiagnosis, DigitCorrection};
+#[cfg(feature = "std")]
+pub use explain::Explanation;
+pub use format::FormatOptions;
+#[cfg(feature = "std")]
+pub use generator::SINGenerator;
+pub use guarded::GuardedSIN;
+#[cfg(feature = "std")]
+pub use id::{identify, CanadianId, IdKind};
+#[cfg(feature = "std")]
+pub use info::SINInfo;
+#[cfg(feature = "std")]
+pub use neq::{NEQParseError, NEQ};
+#[cfg(all(feature = "derive", feature = "serde"))]
+#[doc(hidden)]
+pub use newtype::__private;
+#[cfg(feature = "std")]
+pub use nine_series::{NineSeriesInfo, TemporarySIN};
+#[cfg(feature = "std")]
+pub use ocr::{extract_from_ocr, OcrSubstitution, SINCandidate};
+#[cfg(feature = "std")]
+pub use parse_options::ParseOptions;
+#[cfg(feature = "std")]
+pub use partial::PartialSIN;
+pub use plausibility::Plausibility;
+#[cfg(feature = "std")]
+pub use policy::{PolicyViolation, ValidationPolicy};
+#[cfg(feature = "std")]
+pub use pri::{PRIParseError, PRI};
+#[cfg(feature = "std")]
+pub use pseudonymize::Pseudonymizer;
+#[cfg(feature = "std")]
+pub use qst::QSTNumber;
+#[cfg(feature = "std")]
+pub use range::{SINRange, SINRangeIter};
+#[cfg(feature = "std")]
+pub use scan::{SINMatch, Scanner};
+#[cfg(feature = "serde_with")]
+pub use serde_as::{AsInteger, Dashed, Masked};
+#[cfg(feature = "std")]
+pub use set::{SINSet, SINSetDecodeError};
+pub use typed::{Business, Permanent, SINCategory, Temporary, TypedSIN};
+pub use types::SINTypes;
+#[cfg(feature = "std")]
+pub use uci::{UCIParseError, UCI};
+#[cfg(feature = "clap")]
+pub use value_parser::SINValueParser;
+#[cfg(feature = "std")]
+pub use verified::Verified;
+#[cfg(feature = "std")]
+pub use verify::{MockVerifier, PersonInfo, SINVerifier, VerificationResult};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[non_exhaustive]
@@ -12,8 +193,93 @@ pub enum SINParseError {
     TooShort,
     /// The SIN has an invalid Luhn checksum.
     InvalidChecksum,
+    /// The SIN contains a digit that is greater than 9.
+    InvalidDigit,
+    /// The SIN is a placeholder value that is never issued, such as `000-000-000`.
+    Placeholder,
+    /// The SIN can't be of the expected type.
+    WrongType,
+    /// The SIN contains a character that isn't allowed.
+    InvalidCharacter,
+    /// The SIN has a separator in a place where it isn't allowed.
+    MisplacedSeparator,
+}
+
+/// A language that human-readable strings can be rendered in. Canada has two official languages,
+/// so government-facing applications usually need to support both.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Locale {
+    #[default]
+    English,
+    French,
 }
 
+impl SINParseError {
+    /// A description of the error in the given language.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{Locale, SINParseError};
+    /// assert_eq!(
+    ///     SINParseError::TooShort.message(Locale::French),
+    ///     "NAS trop court (moins de 9 chiffres)",
+    /// );
+    /// ```
+    pub fn message(self, locale: Locale) -> &'static str {
+        use Locale::*;
+        match (self, locale) {
+            (Self::TooLong, English) => "SIN is longer than 9 digits",
+            (Self::TooLong, French) => "NAS trop long (plus de 9 chiffres)",
+            (Self::TooShort, English) => "SIN is less than 9 digits",
+            (Self::TooShort, French) => "NAS trop court (moins de 9 chiffres)",
+            (Self::InvalidChecksum, English) => "SIN has an invalid checksum",
+            (Self::InvalidChecksum, French) => "NAS avec une somme de contrôle invalide",
+            (Self::InvalidDigit, English) => "SIN contains a digit greater than 9",
+            (Self::InvalidDigit, French) => "NAS avec un chiffre supérieur à 9",
+            (Self::Placeholder, English) => "SIN is a placeholder value",
+            (Self::Placeholder, French) => "NAS fictif",
+            (Self::WrongType, English) => "SIN is not of the expected type",
+            (Self::WrongType, French) => "NAS d'un type inattendu",
+            (Self::InvalidCharacter, English) => "SIN contains an invalid character",
+            (Self::InvalidCharacter, French) => "NAS avec un caractère invalide",
+            (Self::MisplacedSeparator, English) => "SIN has a separator in the wrong place",
+            (Self::MisplacedSeparator, French) => "NAS avec un séparateur mal placé",
+        }
+    }
+    /// A short code for the error, such as `invalid_checksum`, which can be checked by programs
+    /// instead of matching on [`SINParseError::message`]. Codes never change for an existing
+    /// variant, but new ones may be added along with new variants.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SINParseError;
+    /// assert_eq!(SINParseError::InvalidChecksum.code(), "invalid_checksum");
+    /// ```
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::TooLong => "too_long",
+            Self::TooShort => "too_short",
+            Self::InvalidChecksum => "invalid_checksum",
+            Self::InvalidDigit => "invalid_digit",
+            Self::Placeholder => "placeholder",
+            Self::WrongType => "wrong_type",
+            Self::InvalidCharacter => "invalid_character",
+            Self::MisplacedSeparator => "misplaced_separator",
+        }
+    }
+}
+
+impl fmt::Display for SINParseError {
+    /// Formats the error in English. Use [`SINParseError::message`] for other languages.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message(Locale::English))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for SINParseError {}
+
 /// Types of SINs: All the provinces, plus some other categories.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[non_exhaustive]
@@ -42,6 +308,45 @@ pub enum SINType {
 }
 
 impl SINType {
+    /// Every type, in declaration order. Use this instead of listing the types by hand, since
+    /// more types may be added.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SINType;
+    /// assert_eq!(SINType::all().next(), Some(SINType::CRAAssigned));
+    /// assert!(SINType::all().any(|ty| ty == SINType::Yukon));
+    /// ```
+    pub fn all() -> impl Iterator<Item = SINType> + Clone {
+        ALL_TYPES.iter().copied()
+    }
+    /// Every type that is a province or territory (see [`SINType::is_province`]), in
+    /// alphabetical order by English name.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SINType;
+    /// assert_eq!(SINType::provinces().count(), 13);
+    /// assert!(SINType::provinces().all(SINType::is_province));
+    /// ```
+    pub fn provinces() -> impl Iterator<Item = SINType> + Clone {
+        Self::all().filter(|ty| ty.is_province())
+    }
+    /// The first digits that a SIN of this type can start with. This is the inverse of
+    /// [`SIN::types`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SINType;
+    /// assert_eq!(SINType::Ontario.possible_first_digits(), vec![4, 5, 6]);
+    /// assert_eq!(SINType::BusinessNumber.possible_first_digits(), vec![7, 8]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn possible_first_digits(self) -> Vec<u8> {
+        (0..=9)
+            .filter(|&digit| SIN::types_for_first_digit(digit).contains(&self))
+            .collect()
+    }
     /// Does the SIN repersent someone in a province?
     pub fn is_province(self) -> bool {
         use SINType::*;
@@ -66,61 +371,537 @@ impl SINType {
     pub fn is_human(self) -> bool {
         !matches!(self, Self::BusinessNumber)
     }
+    /// The name of the type in the given language.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{Locale, SINType};
+    /// assert_eq!(SINType::Quebec.name(Locale::English), "Quebec");
+    /// assert_eq!(SINType::Quebec.name(Locale::French), "Québec");
+    /// ```
+    pub fn name(self, locale: Locale) -> &'static str {
+        use Locale::*;
+        use SINType::*;
+        match (self, locale) {
+            (CRAAssigned, English) => "CRA-assigned",
+            (CRAAssigned, French) => "Attribué par l'ARC",
+            (TemporaryResident, English) => "Temporary resident",
+            (TemporaryResident, French) => "Résident temporaire",
+            (BusinessNumber, English) => "Business number",
+            (BusinessNumber, French) => "Numéro d'entreprise",
+            (OverseasForces, English) => "Overseas forces",
+            (OverseasForces, French) => "Forces armées à l'étranger",
+            (Alberta, _) => "Alberta",
+            (BritishColumbia, English) => "British Columbia",
+            (BritishColumbia, French) => "Colombie-Britannique",
+            (Manitoba, _) => "Manitoba",
+            (NewBrunswick, English) => "New Brunswick",
+            (NewBrunswick, French) => "Nouveau-Brunswick",
+            (NewfoundlandLabrador, English) => "Newfoundland and Labrador",
+            (NewfoundlandLabrador, French) => "Terre-Neuve-et-Labrador",
+            (NorthwestTerritories, English) => "Northwest Territories",
+            (NorthwestTerritories, French) => "Territoires du Nord-Ouest",
+            (NovaScotia, English) => "Nova Scotia",
+            (NovaScotia, French) => "Nouvelle-Écosse",
+            (Nunavut, _) => "Nunavut",
+            (Ontario, _) => "Ontario",
+            (PrinceEdwardIsland, English) => "Prince Edward Island",
+            (PrinceEdwardIsland, French) => "Île-du-Prince-Édouard",
+            (Quebec, English) => "Quebec",
+            (Quebec, French) => "Québec",
+            (Saskatchewan, _) => "Saskatchewan",
+            (Yukon, _) => "Yukon",
+        }
+    }
+    /// The French name of the type, for bilingual applications. This is the same as
+    /// `name(Locale::French)`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SINType;
+    /// assert_eq!(SINType::NovaScotia.name_fr(), "Nouvelle-Écosse");
+    /// assert_eq!(SINType::TemporaryResident.name_fr(), "Résident temporaire");
+    /// ```
+    pub fn name_fr(self) -> &'static str {
+        self.name(Locale::French)
+    }
+    /// The two-letter postal abbreviation of the province or territory, or `None` if the type
+    /// isn't a province.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SINType;
+    /// assert_eq!(SINType::BritishColumbia.abbreviation(), Some("BC"));
+    /// assert_eq!(SINType::TemporaryResident.abbreviation(), None);
+    /// ```
+    pub fn abbreviation(self) -> Option<&'static str> {
+        use SINType::*;
+        Some(match self {
+            Alberta => "AB",
+            BritishColumbia => "BC",
+            Manitoba => "MB",
+            NewBrunswick => "NB",
+            NewfoundlandLabrador => "NL",
+            NorthwestTerritories => "NT",
+            NovaScotia => "NS",
+            Nunavut => "NU",
+            Ontario => "ON",
+            PrinceEdwardIsland => "PE",
+            Quebec => "QC",
+            Saskatchewan => "SK",
+            Yukon => "YT",
+            CRAAssigned | TemporaryResident | BusinessNumber | OverseasForces => return None,
+        })
+    }
+    /// The ISO 3166-2 code of the province or territory, such as `CA-AB`, or `None` if the type
+    /// isn't a province.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SINType;
+    /// assert_eq!(SINType::Alberta.iso_3166_2(), Some("CA-AB"));
+    /// assert_eq!(SINType::OverseasForces.iso_3166_2(), None);
+    /// ```
+    pub fn iso_3166_2(self) -> Option<&'static str> {
+        use SINType::*;
+        Some(match self {
+            Alberta => "CA-AB",
+            BritishColumbia => "CA-BC",
+            Manitoba => "CA-MB",
+            NewBrunswick => "CA-NB",
+            NewfoundlandLabrador => "CA-NL",
+            NorthwestTerritories => "CA-NT",
+            NovaScotia => "CA-NS",
+            Nunavut => "CA-NU",
+            Ontario => "CA-ON",
+            PrinceEdwardIsland => "CA-PE",
+            Quebec => "CA-QC",
+            Saskatchewan => "CA-SK",
+            Yukon => "CA-YT",
+            CRAAssigned | TemporaryResident | BusinessNumber | OverseasForces => return None,
+        })
+    }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+impl fmt::Display for SINType {
+    /// Formats the name of the type in English. Use [`SINType::name`] for other languages.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SINType;
+    /// assert_eq!(SINType::PrinceEdwardIsland.to_string(), "Prince Edward Island");
+    /// assert_eq!(SINType::TemporaryResident.to_string(), "Temporary resident");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name(Locale::English))
+    }
+}
+
+/// Every [`SINType`], in declaration order.
+const ALL_TYPES: [SINType; 17] = {
+    use SINType::*;
+    [
+        CRAAssigned,
+        TemporaryResident,
+        BusinessNumber,
+        OverseasForces,
+        Alberta,
+        BritishColumbia,
+        Manitoba,
+        NewBrunswick,
+        NewfoundlandLabrador,
+        NorthwestTerritories,
+        NovaScotia,
+        Nunavut,
+        Ontario,
+        PrinceEdwardIsland,
+        Quebec,
+        Saskatchewan,
+        Yukon,
+    ]
+};
+
+#[cfg(feature = "std")]
+/// An error from parsing a [`SINType`] from a string that isn't the name of a type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SINTypeParseError;
+
+#[cfg(feature = "std")]
+impl fmt::Display for SINTypeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown SIN type")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for SINTypeParseError {}
+
+#[cfg(feature = "std")]
+impl FromStr for SINType {
+    type Err = SINTypeParseError;
+
+    /// Parses a type from its English or French name, its postal abbreviation, its ISO 3166-2
+    /// code, or the name of the variant. Case, accents, spaces and punctuation are ignored.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SINType;
+    /// assert_eq!("ON".parse(), Ok(SINType::Ontario));
+    /// assert_eq!("Québec".parse(), Ok(SINType::Quebec));
+    /// assert_eq!("ile-du-prince-edouard".parse(), Ok(SINType::PrinceEdwardIsland));
+    /// assert_eq!("CA-NU".parse(), Ok(SINType::Nunavut));
+    /// assert_eq!("TemporaryResident".parse(), Ok(SINType::TemporaryResident));
+    /// assert!("Narnia".parse::<SINType>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let letters: String = names::name_letters(s).collect();
+        if letters.is_empty() {
+            return Err(SINTypeParseError);
+        }
+        ALL_TYPES
+            .iter()
+            .copied()
+            .find(|ty| {
+                let debug = format!("{:?}", ty);
+                [
+                    ty.name(Locale::English),
+                    ty.name(Locale::French),
+                    ty.abbreviation().unwrap_or_default(),
+                    ty.iso_3166_2().unwrap_or_default(),
+                    &debug,
+                ]
+                .iter()
+                .any(|name| names::name_letters(name).eq(letters.chars()))
+            })
+            .ok_or(SINTypeParseError)
+    }
+}
+
+/// SINs that are never issued, but have a valid checksum and are used as placeholders.
+const PLACEHOLDERS: [[u8; 9]; 2] = [[0, 0, 0, 0, 0, 0, 0, 0, 0], [1, 2, 3, 4, 5, 6, 7, 8, 2]];
+
+/// SINs that appear as examples in documentation, tutorials and test suites, stored as numbers.
+/// Add to this list when a new example becomes common.
+const KNOWN_EXAMPLES: [u32; 5] = [
+    // Service Canada's and Wikipedia's example SIN
+    46_454_286,
+    // Common in test data for payroll and tax software
+    130_692_544,
+    193_456_787,
+    // Repeated digits with a valid check digit
+    111_111_118,
+    999_999_998,
+];
+
+#[cfg(feature = "std")]
+/// The number of valid SINs, which is the number of possible first 8 digits.
+const VALID_SINS: u32 = 100_000_000;
+
+#[cfg(feature = "std")]
+/// The number of valid SINs that start with each digit.
+const SINS_PER_FIRST_DIGIT: u32 = VALID_SINS / 10;
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 /// A social insurance number.
 pub struct SIN {
-    inner_digits: [u8; 9],
+    /// The SIN as a number plus one, so `046-454-286` is stored as `46454287`. This keeps `SIN` at
+    /// 4 bytes, and `Option<SIN>` too since the value is never zero, and the numeric order is the
+    /// same as the order of the digits.
+    packed: NonZeroU32,
+}
+
+impl fmt::Debug for SIN {
+    /// Shows the digits of the SIN, since the packed number loses leading zeros.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SIN")
+            .field("inner_digits", &self.digits())
+            .finish()
+    }
 }
 
 impl SIN {
-    /// Parses a SIN from a string.
+    /// Parses a SIN from a string. This accepts anything that can be borrowed as a `str`, such
+    /// as a `String`, a `&str` or a `Cow<str>`, and only reads the text through that borrow,
+    /// without copying it.
     ///
     /// ## Examples
     /// ```
     /// use canada_sin::SIN;
     /// assert!(SIN::parse("046454286".to_string()).is_ok());
+    /// assert!(SIN::parse("046454286").is_ok());
+    /// ```
+    pub fn parse(s: impl AsRef<str>) -> Result<Self, SINParseError> {
+        Self::from_digits(Self::parse_digits(s.as_ref())?)
+    }
+    /// Checks if a string is a valid SIN, accepting the same strings as [`SIN::parse`]. This is
+    /// faster than `SIN::parse(...).is_ok()`, since it doesn't allocate or build a `SIN`, so it's
+    /// useful in hot paths that only need a yes or no answer.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// assert!(SIN::is_valid("046-454-286"));
+    /// assert!(!SIN::is_valid("046-454-287"));
+    /// assert!(!SIN::is_valid("046-454-2860"));
+    /// ```
+    pub fn is_valid(s: &str) -> bool {
+        let mut len = 0;
+        let mut sum = 0;
+        for byte in s.bytes() {
+            if byte.is_ascii_digit() {
+                if len == 9 {
+                    return false;
+                }
+                let digit = byte - b'0';
+                sum += if len % 2 == 1 {
+                    luhn::DOUBLED[usize::from(digit)]
+                } else {
+                    digit
+                };
+                len += 1;
+            }
+        }
+        len == 9 && sum % 10 == 0
+    }
+    /// Creates a SIN from an array of digits, such as one read from a fixed-width file, without
+    /// going through a `String`. This fails if any of the digits are greater than 9, or if the
+    /// checksum is invalid.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{SINParseError, SIN};
+    /// let sin = SIN::from_digits([0, 4, 6, 4, 5, 4, 2, 8, 6]).unwrap();
+    /// assert_eq!(sin.digits_string(), "046454286");
+    /// assert_eq!(
+    ///     SIN::from_digits([0, 4, 6, 4, 5, 4, 2, 8, 7]),
+    ///     Err(SINParseError::InvalidChecksum),
+    /// );
+    /// assert_eq!(
+    ///     SIN::from_digits([0, 4, 6, 4, 5, 4, 2, 8, 10]),
+    ///     Err(SINParseError::InvalidDigit),
+    /// );
+    /// ```
+    pub fn from_digits(digits: [u8; 9]) -> Result<Self, SINParseError> {
+        if digits.iter().any(|&digit| digit > 9) {
+            return Err(SINParseError::InvalidDigit);
+        }
+        if !luhn::is_valid(&digits) {
+            return Err(SINParseError::InvalidChecksum);
+        }
+        Ok(Self::from_valid_digits(digits))
+    }
+    /// Creates a SIN from digits that have already been validated, such as by an FFI layer,
+    /// without checking them again. Since this is a `const fn`, it can also be used for
+    /// constants.
+    ///
+    /// Passing digits greater than 9 or digits with an invalid checksum panics when debug
+    /// assertions are enabled (which makes it a compile error in a constant), and otherwise
+    /// creates a `SIN` that gives meaningless results.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// const EXAMPLE: SIN = SIN::new_unchecked([0, 4, 6, 4, 5, 4, 2, 8, 6]);
+    /// assert_eq!(EXAMPLE, SIN::parse("046454286".to_string()).unwrap());
+    /// ```
+    pub const fn new_unchecked(digits: [u8; 9]) -> Self {
+        debug_assert!(luhn::is_valid_const(&digits), "invalid SIN digits");
+        let mut packed = 0;
+        let mut idx = 0;
+        while idx < 9 {
+            packed = packed * 10 + digits[idx] as u32;
+            idx += 1;
+        }
+        Self::from_packed(packed)
+    }
+    /// Creates a SIN from the number made of its 9 digits.
+    const fn from_packed(packed: u32) -> Self {
+        match NonZeroU32::new(packed + 1) {
+            Some(packed) => Self { packed },
+            None => unreachable!(),
+        }
+    }
+    /// The number made of the 9 digits of the SIN, so `046-454-286` is `46454286`.
+    const fn packed(&self) -> u32 {
+        self.packed.get() - 1
+    }
+    /// Creates a SIN from digits extracted from a string, checking the length and checksum.
+    #[cfg(feature = "std")]
+    fn from_digit_vec(digits: Vec<u8>) -> Result<Self, SINParseError> {
+        let digits = Self::digit_vec_to_array(digits)?;
+        if !luhn::is_valid(&digits) {
+            return Err(SINParseError::InvalidChecksum);
+        }
+        Ok(Self::from_valid_digits(digits))
+    }
+    /// Creates a SIN from digits that are known to have a valid checksum.
+    fn from_valid_digits(digits: [u8; 9]) -> Self {
+        Self::from_packed(
+            digits
+                .iter()
+                .fold(0, |acc, &digit| acc * 10 + u32::from(digit)),
+        )
+    }
+    /// The first digit of the SIN.
+    fn first_digit(&self) -> u8 {
+        (self.packed() / 100_000_000) as u8
+    }
+    /// Parses a SIN from a string, like [`SIN::parse`], but also rejects placeholder values that
+    /// are never issued (see [`SIN::is_placeholder`]).
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{SIN, SINParseError};
+    /// assert!(SIN::parse("000-000-000".to_string()).is_ok());
+    /// assert_eq!(
+    ///     SIN::parse_rejecting_placeholders("000-000-000".to_string()),
+    ///     Err(SINParseError::Placeholder),
+    /// );
+    /// ```
+    pub fn parse_rejecting_placeholders(s: impl AsRef<str>) -> Result<Self, SINParseError> {
+        let sin = Self::parse(s)?;
+        if sin.is_placeholder() {
+            return Err(SINParseError::Placeholder);
+        }
+        Ok(sin)
+    }
+    /// Parses a SIN from a string, like [`SIN::parse`], but fails if the SIN can't be of the type
+    /// `ty`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{SIN, SINParseError, SINType};
+    /// assert!(SIN::parse_expecting("946454287".to_string(), SINType::TemporaryResident).is_ok());
+    /// assert_eq!(
+    ///     SIN::parse_expecting("046454286".to_string(), SINType::TemporaryResident),
+    ///     Err(SINParseError::WrongType),
+    /// );
+    /// ```
+    pub fn parse_expecting(s: impl AsRef<str>, ty: SINType) -> Result<Self, SINParseError> {
+        let sin = Self::parse(s)?;
+        if !sin.could_be(ty) {
+            return Err(SINParseError::WrongType);
+        }
+        Ok(sin)
+    }
+    /// Parses a SIN from a string, like [`SIN::parse`], but also accepts decimal digits from any
+    /// script, such as the full-width digits that often show up in text copied from PDFs. Other
+    /// numeric characters that aren't decimal digits, such as `²` or `½`, are rejected instead of
+    /// being ignored. [`SIN::parse`] only accepts ASCII digits, and ignores all other characters.
+    ///
+    /// ## Examples
     /// ```
-    pub fn parse(s: String) -> Result<Self, SINParseError> {
+    /// use canada_sin::{SIN, SINParseError};
+    /// let sin = SIN::parse_unicode("０４６-４５４-２８６".to_string()).unwrap();
+    /// assert_eq!(sin.digits_string(), "046454286");
+    /// assert_eq!(
+    ///     SIN::parse_unicode("046-454-28⁶".to_string()),
+    ///     Err(SINParseError::InvalidCharacter),
+    /// );
+    /// ```
+    pub fn parse_unicode(s: impl AsRef<str>) -> Result<Self, SINParseError> {
+        let digits = s.as_ref().chars().filter_map(|khar| {
+            if let Some(digit) = unicode::digit_value(khar) {
+                Some(Ok(digit))
+            } else if khar.is_numeric() {
+                Some(Err(SINParseError::InvalidCharacter))
+            } else {
+                None
+            }
+        });
+        Self::from_digits(Self::collect_digits(digits)?)
+    }
+    /// Extracts all the digits from a string, ignoring any other characters.
+    #[cfg(feature = "std")]
+    fn extract_digits(s: &str) -> Vec<u8> {
         let mut digits = Vec::with_capacity(9);
         for khar in s.chars() {
             if let Some(digit) = khar.to_digit(10) {
                 digits.push(digit as u8);
             };
         }
-        match digits.len() {
-            n if n < 9 => return Err(SINParseError::TooShort),
-            n if n > 9 => return Err(SINParseError::TooLong),
-            9 => {
-                // luhn checksum
-                let luhn_sum: u8 = digits
-                    .iter()
-                    .enumerate()
-                    .map(|(idx, digit)| digit * (if idx % 2 == 0 { 1u8 } else { 2u8 }))
-                    .map(|val| {
-                        if val > 9 {
-                            // since 16 turns into 1 + 6, and the max value we will se here is 18,
-                            // this will always give the right value
-                            (val % 10) + 1
-                        } else {
-                            val
-                        }
-                    })
-                    .sum();
-                if luhn_sum % 10 != 0 {
-                    return Err(SINParseError::InvalidChecksum);
-                }
+        digits
+    }
+    /// Extracts the digits from a string, without checking the checksum.
+    fn parse_digits(s: &str) -> Result<[u8; 9], SINParseError> {
+        Self::collect_digits(
+            s.chars()
+                .filter_map(|khar| khar.to_digit(10))
+                .map(|digit| Ok(digit as u8)),
+        )
+    }
+    /// Collects digits into an array, failing with the first error or if there aren't exactly 9
+    /// digits. This doesn't allocate.
+    fn collect_digits(
+        digits: impl IntoIterator<Item = Result<u8, SINParseError>>,
+    ) -> Result<[u8; 9], SINParseError> {
+        let mut array = [0; 9];
+        let mut len = 0;
+        for digit in digits {
+            if let Some(slot) = array.get_mut(len) {
+                *slot = digit?;
+            } else {
+                digit?;
             }
-            _ => unreachable!(),
-        };
-        let boxed_digits = digits.into_boxed_slice();
-        let boxing_result: Result<Box<[u8; 9]>, _> = boxed_digits.try_into();
-        match boxing_result {
-            Ok(val) => Ok(Self { inner_digits: *val }),
-            Err(_) => unreachable!(),
+            len += 1;
+        }
+        match len {
+            n if n < 9 => Err(SINParseError::TooShort),
+            n if n > 9 => Err(SINParseError::TooLong),
+            _ => Ok(array),
+        }
+    }
+    /// Checks that there are exactly 9 digits.
+    #[cfg(feature = "std")]
+    fn digit_vec_to_array(digits: Vec<u8>) -> Result<[u8; 9], SINParseError> {
+        match digits.len() {
+            n if n < 9 => Err(SINParseError::TooShort),
+            n if n > 9 => Err(SINParseError::TooLong),
+            _ => Ok(digits.try_into().unwrap()),
         }
     }
+    /// The number made of the first 8 digits of the SIN. There is exactly one valid SIN for each
+    /// of these numbers, so they can be used to count, index and iterate over SINs.
+    #[cfg(feature = "std")]
+    fn body_number(&self) -> u32 {
+        self.packed() / 10
+    }
+    /// The valid SIN whose first 8 digits make up `number`, which must be less than 100000000.
+    #[cfg(feature = "std")]
+    fn from_body_number(number: u32) -> Self {
+        let mut body = [0; 8];
+        let mut rest = number;
+        for digit in body.iter_mut().rev() {
+            *digit = (rest % 10) as u8;
+            rest /= 10;
+        }
+        Self::from_packed(number * 10 + u32::from(luhn::check_digit(&body)))
+    }
+    /// The valid SIN at `index` in the SINs that start with one of `first_digits`, in the order
+    /// of `first_digits`. `index` must be less than `first_digits.len()` times
+    /// `SINS_PER_FIRST_DIGIT`, so a uniformly random index gives a uniformly random SIN.
+    #[cfg(feature = "std")]
+    fn from_first_digit_index(first_digits: &[u8], index: u32) -> Self {
+        let first_digit = u32::from(first_digits[(index / SINS_PER_FIRST_DIGIT) as usize]);
+        Self::from_body_number(first_digit * SINS_PER_FIRST_DIGIT + index % SINS_PER_FIRST_DIGIT)
+    }
+    /// The SIN as a number, without leading zeros.
+    #[cfg(feature = "serde")]
+    fn to_number(self) -> u32 {
+        self.packed()
+    }
+    /// Creates a SIN from a number, adding back leading zeros and checking the checksum.
+    #[cfg(feature = "serde")]
+    fn from_number(number: u32) -> Result<Self, SINParseError> {
+        if number > 999_999_999 {
+            return Err(SINParseError::TooLong);
+        }
+        let sin = Self::from_packed(number);
+        if !luhn::is_valid(&sin.digits()) {
+            return Err(SINParseError::InvalidChecksum);
+        }
+        Ok(sin)
+    }
     /// All types the SIN *could* be. This will often be multiple options, since this is based on
     /// the first digit, and we are running out of numbers, so there is some overlap. However, the
     /// following can be determined unambiguously:
@@ -143,19 +924,51 @@ impl SIN {
     /// let sin = SIN::parse("734323843".to_string()).unwrap();
     /// assert_eq!(sin.types(), vec![BritishColumbia, Yukon, BusinessNumber]);
     /// ```
+    #[cfg(feature = "std")]
     pub fn types(&self) -> Vec<SINType> {
+        Self::types_for_first_digit(self.first_digit()).to_vec()
+    }
+    /// Could the SIN be of type `ty`? This is the same as checking if [`SIN::types`] contains
+    /// `ty`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{SIN, SINType};
+    /// let sin = SIN::parse("734323843".to_string()).unwrap();
+    /// assert!(sin.could_be(SINType::Yukon));
+    /// assert!(!sin.could_be(SINType::Ontario));
+    /// ```
+    pub fn could_be(&self, ty: SINType) -> bool {
+        Self::types_for_first_digit(self.first_digit()).contains(&ty)
+    }
+    /// Is the SIN definitely of type `ty`? This is only true if `ty` is the only type the SIN
+    /// could be.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{SIN, SINType};
+    /// let sin = SIN::parse("946454287".to_string()).unwrap();
+    /// assert!(sin.is_definitely(SINType::TemporaryResident));
+    /// let sin = SIN::parse("734323843".to_string()).unwrap();
+    /// assert!(!sin.is_definitely(SINType::Yukon));
+    /// ```
+    pub fn is_definitely(&self, ty: SINType) -> bool {
+        Self::types_for_first_digit(self.first_digit()) == [ty]
+    }
+    /// All types a SIN starting with `digit` could be.
+    fn types_for_first_digit(digit: u8) -> &'static [SINType] {
         use SINType::*;
-        match self.inner_digits[0] {
-            0 => vec![CRAAssigned],
-            1 => vec![
+        match digit {
+            0 => &[CRAAssigned],
+            1 => &[
                 NovaScotia,
                 NewBrunswick,
                 PrinceEdwardIsland,
                 NewfoundlandLabrador,
             ],
-            2 | 3 => vec![Quebec],
-            4 | 5 => vec![Ontario, OverseasForces],
-            6 => vec![
+            2 | 3 => &[Quebec],
+            4 | 5 => &[Ontario, OverseasForces],
+            6 => &[
                 Ontario,
                 Manitoba,
                 Saskatchewan,
@@ -163,18 +976,68 @@ impl SIN {
                 NorthwestTerritories,
                 Nunavut,
             ],
-            7 => vec![BritishColumbia, Yukon, BusinessNumber],
-            8 => vec![BusinessNumber],
-            9 => vec![TemporaryResident],
+            7 => &[BritishColumbia, Yukon, BusinessNumber],
+            8 => &[BusinessNumber],
+            9 => &[TemporaryResident],
             _ => unreachable!(),
         }
     }
-    /// Returns the parsed digits as an array of digits.
-    pub fn digits(self) -> [u8; 9] {
-        self.inner_digits
+    /// Is this a placeholder value that is never issued, but has a valid checksum? These show up
+    /// a lot in data where a SIN wasn't actually provided. The placeholders are:
+    /// - `000-000-000`, the only valid SIN made of a single repeated digit
+    /// - `123-456-782`, the digits 1 to 8 followed by a check digit
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// assert!(SIN::parse("000000000".to_string()).unwrap().is_placeholder());
+    /// assert!(!SIN::parse("046454286".to_string()).unwrap().is_placeholder());
+    /// ```
+    pub fn is_placeholder(&self) -> bool {
+        PLACEHOLDERS.contains(&self.digits())
     }
-    fn gen_sin_string_part(part: &[u8]) -> String {
-        part.iter().map(|d| d.to_string()).collect::<String>()
+    /// Is this a SIN that's commonly used as an example in documentation and test data? These
+    /// show up in production data when test records aren't cleaned up, so pipelines may want to
+    /// quarantine them. Placeholders aren't included; use [`SIN::is_placeholder`] for those.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// assert!(SIN::parse("046 454 286".to_string()).unwrap().is_known_example());
+    /// assert!(!SIN::parse("946454287".to_string()).unwrap().is_known_example());
+    /// ```
+    pub fn is_known_example(&self) -> bool {
+        KNOWN_EXAMPLES.contains(&self.packed())
+    }
+    /// The number of positions where the digits of the two SINs differ, from 0 to 9. This is
+    /// useful for finding records where a SIN was probably entered with a typo, such as in
+    /// duplicate detection.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// let a = SIN::parse("046454286".to_string()).unwrap();
+    /// let b = SIN::parse("046454294".to_string()).unwrap();
+    /// assert_eq!(a.distance(&b), 2);
+    /// assert_eq!(a.distance(&a), 0);
+    /// ```
+    pub fn distance(&self, other: &SIN) -> u8 {
+        self.digits()
+            .iter()
+            .zip(other.digits().iter())
+            .filter(|(a, b)| a != b)
+            .count() as u8
+    }
+    /// Returns the parsed digits as an array of digits. SINs are stored as a packed number, so
+    /// the digits are computed on each call, and can't be borrowed.
+    pub fn digits(self) -> [u8; 9] {
+        let mut digits = [0; 9];
+        let mut rest = self.packed();
+        for digit in digits.iter_mut().rev() {
+            *digit = (rest % 10) as u8;
+            rest /= 10;
+        }
+        digits
     }
     /// Returns the SIN as a string.
     ///
@@ -184,8 +1047,9 @@ impl SIN {
     /// let sin = SIN::parse("046454286".to_string()).unwrap();
     /// assert_eq!(sin.digits_string(), "046454286")
     /// ```
+    #[cfg(feature = "std")]
     pub fn digits_string(self) -> String {
-        Self::gen_sin_string_part(&self.inner_digits)
+        self.format_with(&FormatOptions::new().without_separator())
     }
     /// Returns the SIN as a string with dashes in it.
     /// ## Examples
@@ -194,18 +1058,26 @@ impl SIN {
     /// let sin = SIN::parse("046454286".to_string()).unwrap();
     /// assert_eq!(sin.digits_dashed_string(), "046-454-286")
     /// ```
+    #[cfg(feature = "std")]
     pub fn digits_dashed_string(self) -> String {
-        format!(
-            "{}-{}-{}",
-            Self::gen_sin_string_part(&self.inner_digits[0..3]),
-            Self::gen_sin_string_part(&self.inner_digits[3..6]),
-            Self::gen_sin_string_part(&self.inner_digits[6..9]),
-        )
+        self.format_with(&FormatOptions::new())
+    }
+    /// Returns the SIN as a string with spaces in it, which is how SINs are printed on cards.
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// assert_eq!(sin.digits_spaced_string(), "046 454 286")
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn digits_spaced_string(self) -> String {
+        self.format_with(&FormatOptions::new().with_separator(' '))
     }
 }
 
 impl fmt::Display for SIN {
-    /// Formats the SIN into three parts with dashes.
+    /// Formats the SIN into three parts with dashes. The alternate flag (`{:#}`) formats the SIN
+    /// without dashes, and width, fill and alignment are supported.
     ///
     /// ## Examples
     /// ```
@@ -214,17 +1086,58 @@ impl fmt::Display for SIN {
     ///     format!("Your SIN is {}.", SIN::parse("046454286".to_string()).unwrap()),
     ///     "Your SIN is 046-454-286.".to_string(),
     /// );
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// assert_eq!(format!("{:#}", sin), "046454286");
+    /// assert_eq!(format!("[{:>13}]", sin), "[  046-454-286]");
+    /// assert_eq!(format!("[{:*^#13}]", sin), "[**046454286**]");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.digits_dashed_string())
+        let mut buf = [0; 11];
+        f.pad(self.format_into_buffer(&mut buf, !f.alternate()))
+    }
+}
+
+impl FromStr for SIN {
+    type Err = SINParseError;
+
+    /// Parses a SIN from a string. This is the same as [`SIN::parse`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// let sin: SIN = "046-454-286".parse().unwrap();
+    /// assert_eq!(sin.digits_string(), "046454286");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<Cow<'_, str>> for SIN {
+    type Error = SINParseError;
+
+    /// Parses a SIN from text that may or may not be owned, such as the result of
+    /// [`String::from_utf8_lossy`]. This is the same as [`SIN::parse`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// use std::{borrow::Cow, convert::TryFrom};
+    /// let sin = SIN::try_from(String::from_utf8_lossy(b"046-454-286")).unwrap();
+    /// assert_eq!(sin, SIN::try_from(Cow::Owned("046454286".to_string())).unwrap());
+    /// ```
+    fn try_from(s: Cow<'_, str>) -> Result<Self, Self::Error> {
+        Self::parse(s)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::unnecessary_to_owned)]
     fn parse_sin_checks_luhn() {
         assert_eq!(
             SIN::parse("123456789".to_string()),
@@ -252,6 +1165,20 @@ mod tests {
     }
 
     #[test]
+    fn parse_borrowed_and_owned() {
+        let owned = String::from("046 454 286");
+        let sin = SIN::parse(&owned).unwrap();
+        assert_eq!(SIN::parse(owned), Ok(sin));
+        assert_eq!(SIN::parse("046 454 286"), Ok(sin));
+        assert_eq!(SIN::parse(Cow::Borrowed("046 454 286")), Ok(sin));
+        assert_eq!(
+            SIN::try_from(Cow::Borrowed("046 454 287")),
+            Err(SINParseError::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
     fn parse_sin_checks_too_short() {
         assert_eq!(
             SIN::parse("12345678".to_string()),
@@ -263,6 +1190,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_to_owned)]
     fn parse_sin_checks_too_long() {
         assert_eq!(
             SIN::parse("0000000000".to_string()),
@@ -291,6 +1219,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_to_owned)]
     fn digits_string() {
         let sin = SIN::parse("000-000-000".to_string()).unwrap();
         assert_eq!(sin.digits_string(), "000000000");
@@ -299,10 +1228,158 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_to_owned)]
     fn digits_dashed_string() {
         let sin = SIN::parse("000-000-000".to_string()).unwrap();
         assert_eq!(sin.digits_dashed_string(), "000-000-000");
         let sin = SIN::parse("999999998".to_string()).unwrap();
         assert_eq!(sin.digits_dashed_string(), "999-999-998");
     }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn digits_spaced_string() {
+        let sin = SIN::parse("000-000-000".to_string()).unwrap();
+        assert_eq!(sin.digits_spaced_string(), "000 000 000");
+        let sin = SIN::parse("999999998".to_string()).unwrap();
+        assert_eq!(sin.digits_spaced_string(), "999 999 998");
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn parse_rejecting_placeholders() {
+        assert_eq!(
+            SIN::parse_rejecting_placeholders("123-456-782".to_string()),
+            Err(SINParseError::Placeholder)
+        );
+        assert_eq!(
+            SIN::parse_rejecting_placeholders("123-456-789".to_string()),
+            Err(SINParseError::InvalidChecksum)
+        );
+        assert!(SIN::parse_rejecting_placeholders("046454286".to_string()).is_ok());
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn parse_expecting() {
+        assert!(SIN::parse_expecting("734323843".to_string(), SINType::BusinessNumber).is_ok());
+        assert!(SIN::parse_expecting("734323843".to_string(), SINType::Yukon).is_ok());
+        assert_eq!(
+            SIN::parse_expecting("734323843".to_string(), SINType::Ontario),
+            Err(SINParseError::WrongType)
+        );
+        assert_eq!(
+            SIN::parse_expecting("734323844".to_string(), SINType::Ontario),
+            Err(SINParseError::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn display_flags() {
+        let sin = SIN::parse("999999998".to_string()).unwrap();
+        assert_eq!(format!("{}", sin), "999-999-998");
+        assert_eq!(format!("{:#}", sin), "999999998");
+        assert_eq!(format!("{:<12}|", sin), "999-999-998 |");
+        assert_eq!(format!("{:-<#10}|", sin), "999999998-|");
+        assert_eq!(format!("{:5}", sin), "999-999-998");
+    }
+
+    #[test]
+    fn error_messages() {
+        assert_eq!(
+            SINParseError::InvalidChecksum.to_string(),
+            SINParseError::InvalidChecksum.message(Locale::English)
+        );
+        assert_eq!(
+            SINParseError::TooLong.message(Locale::French),
+            "NAS trop long (plus de 9 chiffres)"
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn packed_representation() {
+        assert_eq!(std::mem::size_of::<SIN>(), 4);
+        assert_eq!(std::mem::size_of::<Option<SIN>>(), 4);
+        let low = SIN::parse("046454286".to_string()).unwrap();
+        let high = SIN::parse("946454287".to_string()).unwrap();
+        assert!(low < high);
+        assert_eq!(low.digits(), [0, 4, 6, 4, 5, 4, 2, 8, 6]);
+        assert_eq!(SIN::from_body_number(4645428), low);
+        assert_eq!(
+            format!("{:?}", low),
+            "SIN { inner_digits: [0, 4, 6, 4, 5, 4, 2, 8, 6] }"
+        );
+    }
+
+    #[test]
+    fn known_examples_are_valid() {
+        for &number in &KNOWN_EXAMPLES {
+            let sin = SIN::from_packed(number);
+            assert!(luhn::is_valid(&sin.digits()), "{} is invalid", sin);
+            assert!(!sin.is_placeholder());
+        }
+    }
+
+    #[test]
+    fn province_codes() {
+        for ty in SINType::all() {
+            assert_eq!(ty.abbreviation().is_some(), ty.is_province());
+            assert_eq!(
+                ty.iso_3166_2().map(|code| code[3..].to_string()),
+                ty.abbreviation().map(str::to_string)
+            );
+        }
+    }
+
+    #[test]
+    fn parse_types() {
+        for &ty in &ALL_TYPES {
+            assert_eq!(ty.to_string().parse(), Ok(ty));
+            assert_eq!(ty.name_fr().parse(), Ok(ty));
+            assert_eq!(format!("{:?}", ty).parse(), Ok(ty));
+        }
+        assert_eq!(" nl ".parse(), Ok(SINType::NewfoundlandLabrador));
+        assert_eq!("".parse::<SINType>(), Err(SINTypeParseError));
+    }
+
+    #[test]
+    fn every_type_has_a_first_digit() {
+        for ty in SINType::all() {
+            assert!(!ty.possible_first_digits().is_empty(), "{:?}", ty);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn is_valid_matches_parse() {
+        for s in [
+            "",
+            "046454286",
+            "046 454 286",
+            "0464542860",
+            "046454287",
+            "٠٤٦٤٥٤٢٨٦",
+        ] {
+            assert_eq!(SIN::is_valid(s), SIN::parse(s.to_string()).is_ok(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn from_digits_matches_parse() {
+        for sin in [46_454_286, 130_692_544, 999_999_998] {
+            let sin = SIN::from_packed(sin);
+            assert_eq!(SIN::from_digits(sin.digits()), Ok(sin));
+        }
+        assert_eq!(SIN::from_digits([0; 9]), Ok(SIN::from_packed(0)));
+        assert_eq!(SIN::from_digits([255; 9]), Err(SINParseError::InvalidDigit));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "invalid SIN digits")]
+    fn new_unchecked_asserts() {
+        SIN::new_unchecked([0, 4, 6, 4, 5, 4, 2, 8, 7]);
+    }
 }
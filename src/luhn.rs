@@ -0,0 +1,96 @@
+//! Helpers for the Luhn checksum, which is used by SINs and several other identifiers.
+
+/// The value of a digit at the given position, counting from the right starting at 0.
+fn weighted(idx: usize, digit: u8) -> u32 {
+    let digit = u32::from(digit);
+    if idx % 2 == 1 {
+        // since 16 turns into 1 + 6, and the max value we will see here is 18, this will always
+        // give the right value
+        let doubled = digit * 2;
+        if doubled > 9 {
+            (doubled % 10) + 1
+        } else {
+            doubled
+        }
+    } else {
+        digit
+    }
+}
+
+/// Each digit doubled, with the digits of the result added together.
+pub(crate) const DOUBLED: [u8; 10] = [0, 2, 4, 6, 8, 1, 3, 5, 7, 9];
+
+/// The Luhn sum of some digits, including the check digit.
+pub(crate) fn sum(digits: &[u8]) -> u32 {
+    digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(idx, &digit)| weighted(idx, digit))
+        .sum()
+}
+
+/// Whether the digits (including the check digit) have a valid Luhn checksum.
+pub(crate) fn is_valid(digits: &[u8]) -> bool {
+    sum(digits) % 10 == 0
+}
+
+/// Whether 9 digits have a valid Luhn checksum and are all at most 9, in a form that can be used
+/// in constants.
+pub(crate) const fn is_valid_const(digits: &[u8; 9]) -> bool {
+    let mut sum = 0;
+    let mut idx = 0;
+    while idx < 9 {
+        let digit = digits[idx];
+        if digit > 9 {
+            return false;
+        }
+        sum += if idx % 2 == 1 {
+            DOUBLED[digit as usize]
+        } else {
+            digit
+        };
+        idx += 1;
+    }
+    sum % 10 == 0
+}
+
+/// The check digit that needs to be appended to `payload` to make it valid.
+#[cfg(feature = "std")]
+pub(crate) fn check_digit(payload: &[u8]) -> u8 {
+    let sum: u32 = payload
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(idx, &digit)| weighted(idx + 1, digit))
+        .sum();
+    ((10 - sum % 10) % 10) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn check_digit_makes_valid() {
+        assert_eq!(check_digit(&[0, 4, 6, 4, 5, 4, 2, 8]), 6);
+    }
+
+    #[test]
+    fn valid_digits() {
+        assert!(is_valid(&[0, 4, 6, 4, 5, 4, 2, 8, 6]));
+        assert!(!is_valid(&[0, 4, 6, 4, 5, 4, 2, 8, 7]));
+        assert_eq!(sum(&[0, 4, 6, 4, 5, 4, 2, 8, 6]), 50);
+        assert!(is_valid_const(&[0, 4, 6, 4, 5, 4, 2, 8, 6]));
+        assert!(!is_valid_const(&[0, 4, 6, 4, 5, 4, 2, 8, 7]));
+        assert!(!is_valid_const(&[0, 4, 6, 4, 5, 4, 2, 8, 16]));
+    }
+
+    #[test]
+    fn doubled_table() {
+        for digit in 0..10 {
+            assert_eq!(u32::from(DOUBLED[usize::from(digit)]), weighted(1, digit));
+        }
+    }
+}
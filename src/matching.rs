@@ -0,0 +1,139 @@
+//! Checking a SIN against partial information, such as the last few digits given by a customer.
+
+use crate::{parse_options::DEFAULT_SEPARATORS, SIN};
+
+/// Characters that stand for a hidden digit in a masked SIN.
+const WILDCARDS: [char; 4] = ['*', 'X', 'x', '•'];
+
+impl SIN {
+    /// The last 3 digits of the SIN, which are often used to confirm someone's identity.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// assert_eq!(sin.last_three(), [2, 8, 6]);
+    /// ```
+    pub fn last_three(&self) -> [u8; 3] {
+        let digits = self.digits();
+        [digits[6], digits[7], digits[8]]
+    }
+    /// Checks if the SIN ends with the digits in `suffix`, such as the last 3 digits read out by
+    /// a caller. Characters that aren't digits are ignored. This returns false if `suffix` has no
+    /// digits or more than 9 digits, so an empty answer never matches.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// assert!(sin.matches_last_digits("286"));
+    /// assert!(sin.matches_last_digits("4-286"));
+    /// assert!(!sin.matches_last_digits("287"));
+    /// assert!(!sin.matches_last_digits(""));
+    /// ```
+    pub fn matches_last_digits(&self, suffix: &str) -> bool {
+        let suffix = Self::extract_digits(suffix);
+        !suffix.is_empty() && suffix.len() <= 9 && self.digits().ends_with(&suffix)
+    }
+    /// Checks if the SIN matches a masked pattern, such as `***-**6-286` from a statement where
+    /// only some digits are shown. The pattern must have 9 positions, each of which is a digit
+    /// or a wildcard (`*`, `X`, `x` or `•`). Separators are optional, and the same separators as
+    /// [`ParseOptions::new`](crate::ParseOptions::new) are allowed. Any other character means the
+    /// pattern doesn't match.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// assert!(sin.matches_masked("***-**4-286"));
+    /// assert!(sin.matches_masked("XXXXX4286"));
+    /// assert!(!sin.matches_masked("***-**5-286"));
+    /// assert!(!sin.matches_masked("**-**4-286"));
+    /// ```
+    pub fn matches_masked(&self, pattern: &str) -> bool {
+        let digits = self.digits();
+        let mut idx = 0;
+        for khar in pattern.trim().chars() {
+            if DEFAULT_SEPARATORS.contains(&khar) {
+                continue;
+            }
+            let expected = match digits.get(idx) {
+                Some(&digit) => digit,
+                None => return false,
+            };
+            let matches = match khar.to_digit(10) {
+                Some(digit) => digit as u8 == expected,
+                None => WILDCARDS.contains(&khar),
+            };
+            if !matches {
+                return false;
+            }
+            idx += 1;
+        }
+        idx == 9
+    }
+    /// Checks if a string is this SIN, ignoring how it's formatted. Whitespace and the same
+    /// separators as [`SIN::canonicalize`] are ignored, and any other character means the string
+    /// doesn't match. This doesn't allocate, and never fails, so it's simpler than parsing the
+    /// string and comparing.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// assert!(sin.eq_str("046 454 286"));
+    /// assert!(sin.eq_str(" 046-454-286 "));
+    /// assert!(!sin.eq_str("046-454-287"));
+    /// assert!(!sin.eq_str("SIN 046454286"));
+    /// ```
+    pub fn eq_str(&self, s: &str) -> bool {
+        let digits = self.digits();
+        let mut idx = 0;
+        for khar in s.chars() {
+            if khar.is_ascii_digit() {
+                if digits.get(idx) != Some(&(khar as u8 - b'0')) {
+                    return false;
+                }
+                idx += 1;
+            } else if !khar.is_whitespace() && !DEFAULT_SEPARATORS.contains(&khar) {
+                return false;
+            }
+        }
+        idx == 9
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn last_digits() {
+        let sin = SIN::parse("046454286".to_string()).unwrap();
+        assert!(sin.matches_last_digits("046 454 286"));
+        assert!(!sin.matches_last_digits("0046454286"));
+        assert!(!sin.matches_last_digits("abc"));
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn masked_patterns() {
+        let sin = SIN::parse("046454286".to_string()).unwrap();
+        assert!(sin.matches_masked(" ••• ••• 286 "));
+        assert!(sin.matches_masked("046454286"));
+        assert!(!sin.matches_masked("*********0"));
+        assert!(!sin.matches_masked("***/***/286"));
+        assert!(!sin.matches_masked(""));
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn eq_str_lengths() {
+        let sin = SIN::parse("046454286".to_string()).unwrap();
+        assert!(sin.eq_str("046\u{a0}454·286"));
+        assert!(!sin.eq_str("04645428"));
+        assert!(!sin.eq_str("0464542860"));
+        assert!(!sin.eq_str(""));
+    }
+}
@@ -0,0 +1,11 @@
+//! Helpers for weighted modulus 11 check digits, which are used by several identifiers.
+
+/// The remainder after dividing the weighted sum of the digits by 11.
+pub(crate) fn remainder(digits: &[u8], weights: &[u32]) -> u32 {
+    digits
+        .iter()
+        .zip(weights)
+        .map(|(&digit, weight)| u32::from(digit) * weight)
+        .sum::<u32>()
+        % 11
+}
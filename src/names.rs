@@ -0,0 +1,23 @@
+//! Helpers for comparing names with the letters encoded in identifiers.
+
+/// Converts a name to the letters used in identifiers: uppercase ASCII letters, with accents
+/// removed and everything else dropped.
+pub(crate) fn name_letters(name: &str) -> impl Iterator<Item = char> + '_ {
+    name.chars().filter_map(|c| {
+        let c = match c {
+            'à' | 'â' | 'ä' | 'À' | 'Â' | 'Ä' => 'A',
+            'ç' | 'Ç' => 'C',
+            'é' | 'è' | 'ê' | 'ë' | 'É' | 'È' | 'Ê' | 'Ë' => 'E',
+            'î' | 'ï' | 'Î' | 'Ï' => 'I',
+            'ô' | 'ö' | 'Ô' | 'Ö' => 'O',
+            'ù' | 'û' | 'ü' | 'Ù' | 'Û' | 'Ü' => 'U',
+            'ÿ' | 'Ÿ' => 'Y',
+            c => c.to_ascii_uppercase(),
+        };
+        if c.is_ascii_uppercase() {
+            Some(c)
+        } else {
+            None
+        }
+    })
+}
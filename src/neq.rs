@@ -0,0 +1,154 @@
+//! Québec enterprise numbers.
+
+use crate::{mod11, parse_options::DEFAULT_SEPARATORS};
+use std::{convert::TryInto, error::Error, fmt, str::FromStr};
+
+/// The first two digits of each kind of NEQ.
+const PREFIXES: [[u8; 2]; 4] = [[1, 1], [2, 2], [3, 3], [8, 8]];
+
+/// The weights of the first 9 digits for the check digit.
+const WEIGHTS: [u32; 9] = [4, 3, 2, 7, 6, 5, 4, 3, 2];
+
+/// An error resulting from parsing a NEQ.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NEQParseError {
+    /// The NEQ is longer than 10 digits.
+    TooLong,
+    /// The NEQ is less than 10 digits.
+    TooShort,
+    /// The NEQ contains a character that isn't a digit or a separator.
+    InvalidCharacter,
+    /// The NEQ doesn't start with `11`, `22`, `33` or `88`.
+    InvalidPrefix,
+    /// The check digit of the NEQ is wrong.
+    InvalidChecksum,
+}
+
+impl fmt::Display for NEQParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::TooLong => "NEQ is longer than 10 digits",
+            Self::TooShort => "NEQ is less than 10 digits",
+            Self::InvalidCharacter => "NEQ contains an invalid character",
+            Self::InvalidPrefix => "NEQ has an invalid prefix",
+            Self::InvalidChecksum => "NEQ has an invalid checksum",
+        })
+    }
+}
+
+impl Error for NEQParseError {}
+
+/// A Québec enterprise number (numéro d'entreprise du Québec), the 10-digit number assigned by the
+/// Registraire des entreprises to businesses registered in Québec. These are separate from federal
+/// business numbers.
+///
+/// The first two digits are the kind of registration: `11` for corporations, `22` for sole
+/// proprietorships, `33` for partnerships and `88` for other legal persons. The last digit is a
+/// weighted modulus 11 check digit.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct NEQ {
+    digits: [u8; 10],
+}
+
+impl NEQ {
+    /// Parses a NEQ from a string. Whitespace and separators like `-` are ignored, but any
+    /// other character is an error.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{NEQ, NEQParseError};
+    /// assert!(NEQ::parse("1143920115".to_string()).is_ok());
+    /// assert_eq!(NEQ::parse("114392011".to_string()), Err(NEQParseError::TooShort));
+    /// assert_eq!(NEQ::parse("1143920116".to_string()), Err(NEQParseError::InvalidChecksum));
+    /// assert_eq!(NEQ::parse("9698658215".to_string()), Err(NEQParseError::InvalidPrefix));
+    /// ```
+    pub fn parse(s: String) -> Result<Self, NEQParseError> {
+        let mut digits = Vec::with_capacity(10);
+        for khar in s.chars() {
+            if let Some(digit) = khar.to_digit(10) {
+                digits.push(digit as u8);
+            } else if !khar.is_whitespace() && !DEFAULT_SEPARATORS.contains(&khar) {
+                return Err(NEQParseError::InvalidCharacter);
+            }
+        }
+        let digits: [u8; 10] = match digits.len() {
+            n if n < 10 => return Err(NEQParseError::TooShort),
+            n if n > 10 => return Err(NEQParseError::TooLong),
+            _ => digits.try_into().unwrap(),
+        };
+        if !PREFIXES.contains(&[digits[0], digits[1]]) {
+            return Err(NEQParseError::InvalidPrefix);
+        }
+        let check_digit = (11 - mod11::remainder(&digits[..9], &WEIGHTS)) % 11;
+        if check_digit != u32::from(digits[9]) {
+            return Err(NEQParseError::InvalidChecksum);
+        }
+        Ok(Self { digits })
+    }
+    /// Returns the digits of the NEQ.
+    pub fn digits(self) -> [u8; 10] {
+        self.digits
+    }
+    /// Returns the NEQ as a string of 10 digits.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::NEQ;
+    /// let neq = NEQ::parse("1143 920 115".to_string()).unwrap();
+    /// assert_eq!(neq.digits_string(), "1143920115");
+    /// ```
+    pub fn digits_string(self) -> String {
+        self.digits.iter().map(|d| d.to_string()).collect()
+    }
+}
+
+impl FromStr for NEQ {
+    type Err = NEQParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.to_string())
+    }
+}
+
+impl fmt::Display for NEQ {
+    /// Formats the NEQ as 10 digits, which is how the Registraire des entreprises displays them.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.digits_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_neq() {
+        let neq: NEQ = "1143-920-115".parse().unwrap();
+        assert_eq!(neq.to_string(), "1143920115");
+        assert_eq!(
+            NEQ::parse("11439201150".to_string()),
+            Err(NEQParseError::TooLong)
+        );
+        assert_eq!(NEQ::parse("".to_string()), Err(NEQParseError::TooShort));
+    }
+
+    #[test]
+    fn neq_rules() {
+        assert!(NEQ::parse("2245678900".to_string()).is_ok());
+        assert!(NEQ::parse("3345678904".to_string()).is_ok());
+        assert!(NEQ::parse("8812345673 ".to_string()).is_ok());
+        assert_eq!(
+            NEQ::parse("abc1143920115".to_string()),
+            Err(NEQParseError::InvalidCharacter)
+        );
+        assert_eq!(
+            NEQ::parse("4445678900".to_string()),
+            Err(NEQParseError::InvalidPrefix)
+        );
+        assert_eq!(
+            NEQ::parse("2245678901".to_string()),
+            Err(NEQParseError::InvalidChecksum)
+        );
+    }
+}
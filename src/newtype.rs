@@ -0,0 +1,42 @@
+//! Support code for the `SINNewtype` derive macro. Whether the derived types implement serde's
+//! traits depends on whether this crate has the `serde` feature, which the derive macro can't
+//! see, so the macro defers to `__sin_newtype_serde!`, which is defined differently depending on
+//! the feature.
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub mod __private {
+    pub use ::serde;
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sin_newtype_serde {
+    ($name:ident) => {
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S: $crate::__private::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> ::std::result::Result<S::Ok, S::Error> {
+                $crate::__private::serde::Serialize::serialize(&self.sin(), serializer)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D: $crate::__private::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> ::std::result::Result<Self, D::Error> {
+                <$crate::SIN as $crate::__private::serde::Deserialize>::deserialize(deserializer)
+                    .map(Self::from)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sin_newtype_serde {
+    ($name:ident) => {};
+}
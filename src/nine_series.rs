@@ -0,0 +1,98 @@
+//! SINs in the 900 series, which are given to temporary residents.
+
+use crate::{Date, SIN};
+
+/// The rules that apply to SINs in the 900 series, as returned by [`SIN::nine_series_info`]. These
+/// SINs are given to people who are neither Canadian citizens nor permanent residents, such as
+/// people with a work or study permit.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct NineSeriesInfo {
+    /// The SIN expires on the same day as the immigration document that it was issued for.
+    pub expires: bool,
+    /// Employers need to see an immigration document that allows the holder to work in Canada.
+    pub requires_work_authorization: bool,
+    /// Employers need to keep track of the expiry date, and check that it has been extended
+    /// before it passes.
+    pub employer_must_track_expiry: bool,
+    /// The holder gets a new SIN that doesn't start with 9 if they become a permanent resident or
+    /// citizen.
+    pub replaced_on_permanent_residence: bool,
+}
+
+impl SIN {
+    /// Is the SIN in the 900 series (starting with 9), which is given to temporary residents?
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// assert!(SIN::parse("946454287".to_string()).unwrap().is_nine_series());
+    /// assert!(!SIN::parse("046454286".to_string()).unwrap().is_nine_series());
+    /// ```
+    pub fn is_nine_series(&self) -> bool {
+        self.first_digit() == 9
+    }
+    /// Returns the rules for SINs in the 900 series, or `None` if the SIN isn't in the 900 series.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// let info = SIN::parse("946454287".to_string()).unwrap().nine_series_info().unwrap();
+    /// assert!(info.expires);
+    /// assert!(SIN::parse("046454286".to_string()).unwrap().nine_series_info().is_none());
+    /// ```
+    pub fn nine_series_info(&self) -> Option<NineSeriesInfo> {
+        if self.is_nine_series() {
+            Some(NineSeriesInfo {
+                expires: true,
+                requires_work_authorization: true,
+                employer_must_track_expiry: true,
+                replaced_on_permanent_residence: true,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// A SIN in the 900 series, along with the date that it expires.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{Date, TemporarySIN, SIN};
+/// let sin = SIN::parse("946454287".to_string()).unwrap();
+/// let temporary = TemporarySIN::new(sin, Date::new(2030, 6, 30).unwrap()).unwrap();
+/// assert!(!temporary.is_expired(Date::new(2030, 6, 30).unwrap()));
+/// assert!(temporary.is_expired(Date::new(2030, 7, 1).unwrap()));
+///
+/// let sin = SIN::parse("046454286".to_string()).unwrap();
+/// assert!(TemporarySIN::new(sin, Date::new(2030, 6, 30).unwrap()).is_none());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TemporarySIN {
+    sin: SIN,
+    expiry: Date,
+}
+
+impl TemporarySIN {
+    /// Creates a temporary SIN, returning `None` if the SIN isn't in the 900 series.
+    pub fn new(sin: SIN, expiry: Date) -> Option<Self> {
+        if sin.is_nine_series() {
+            Some(Self { sin, expiry })
+        } else {
+            None
+        }
+    }
+    /// The SIN.
+    pub fn sin(&self) -> SIN {
+        self.sin
+    }
+    /// The last day that the SIN is valid.
+    pub fn expiry(&self) -> Date {
+        self.expiry
+    }
+    /// Has the SIN expired by `as_of`? The SIN is still valid on its expiry date.
+    pub fn is_expired(&self, as_of: Date) -> bool {
+        as_of > self.expiry
+    }
+}
@@ -0,0 +1,287 @@
+//! Parsing SINs from text produced by optical character recognition (OCR).
+
+use crate::{
+    scan::{contains_keyword, DEFAULT_KEYWORDS},
+    SINParseError, SIN,
+};
+
+/// A character that was replaced with a digit by [`SIN::parse_ocr`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct OcrSubstitution {
+    /// The index of the character in the input, counted in `char`s.
+    pub position: usize,
+    /// The character that was in the input.
+    pub found: char,
+    /// The digit it was replaced with.
+    pub digit: u8,
+}
+
+/// A possible SIN found by [`extract_from_ocr`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SINCandidate {
+    /// The SIN that was found.
+    pub sin: SIN,
+    /// The byte offset where the SIN starts.
+    pub start: usize,
+    /// The byte offset just after the end of the SIN.
+    pub end: usize,
+    /// The characters that were replaced with digits. Positions are counted in `char`s from the
+    /// start of the whole text.
+    pub substitutions: Vec<OcrSubstitution>,
+    /// Is there a label like "SIN" or "NAS" on the same line or the line before?
+    pub anchored: bool,
+    /// How likely the candidate is to be a SIN that was read correctly, from 0 to 1. Candidates
+    /// that are labelled and formatted like a SIN score higher, and each substitution or line
+    /// break inside the number lowers the score.
+    pub confidence: f32,
+}
+
+/// A word of OCR text that could be part of a SIN.
+struct Token {
+    start: usize,
+    end: usize,
+    digits: Vec<u8>,
+    substitutions: Vec<OcrSubstitution>,
+    after_line_break: bool,
+}
+
+/// Can `khar` be part of a word?
+fn is_word_char(khar: char) -> bool {
+    khar.is_alphanumeric() || matches!(khar, '-' | '.' | '|')
+}
+
+/// Splits OCR text into groups of adjacent words that could be parts of SINs, which are only
+/// separated by whitespace.
+fn token_groups(text: &str) -> Vec<Vec<Token>> {
+    let mut groups = vec![Vec::new()];
+    let mut chars = text.char_indices().enumerate().peekable();
+    let mut line_break = false;
+    let mut punctuation = false;
+    while let Some((position, (start, khar))) = chars.next() {
+        if !is_word_char(khar) {
+            line_break |= khar == '\n';
+            punctuation |= !khar.is_whitespace();
+            continue;
+        }
+        let mut token = Token {
+            start,
+            end: start,
+            digits: Vec::new(),
+            substitutions: Vec::new(),
+            after_line_break: line_break,
+        };
+        let mut is_number = true;
+        let mut has_digit = false;
+        let mut current = Some((position, (start, khar)));
+        while let Some((position, (idx, khar))) = current {
+            token.end = idx + khar.len_utf8();
+            if khar.is_ascii_digit() {
+                token.digits.push(khar as u8 - b'0');
+                has_digit = true;
+            } else if let Some(digit) = confused_digit(khar) {
+                token.digits.push(digit);
+                token.substitutions.push(OcrSubstitution {
+                    position,
+                    found: khar,
+                    digit,
+                });
+            } else if khar != '-' && khar != '.' {
+                is_number = false;
+            }
+            current = chars.next_if(|&(_, (_, khar))| is_word_char(khar));
+        }
+        if !is_number || !has_digit {
+            groups.push(Vec::new());
+        } else if punctuation {
+            groups.push(vec![token]);
+        } else {
+            groups.last_mut().unwrap().push(token);
+        }
+        line_break = false;
+        punctuation = false;
+    }
+    groups
+}
+
+/// Is there a label like "SIN" between the start of the line before `start` and `start`?
+fn is_anchored(text: &str, start: usize) -> bool {
+    let before = &text[..start];
+    let line_start = before.rfind('\n').unwrap_or(0);
+    let context_start = before[..line_start].rfind('\n').map_or(0, |idx| idx + 1);
+    let context = before[context_start..].to_lowercase();
+    DEFAULT_KEYWORDS
+        .iter()
+        .any(|keyword| contains_keyword(&context, keyword))
+}
+
+/// Finds SINs in a block of OCR text, such as a scanned SIN card or T4 slip. Unlike
+/// [`SIN::parse_ocr`], which expects a single number, this looks through the whole text, and
+/// handles numbers that are split across lines. Characters that OCR confuses with digits are
+/// only replaced in words that contain at least one real digit, so words like "SIN" are left
+/// alone. Overlapping candidates are resolved by keeping the most confident one, and candidates
+/// are returned in the order they appear.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::extract_from_ocr;
+/// let text = "EMPLOYEE: J. DOE\nSIN/NAS\nO46 454\n286\nBox 14: 52000.00";
+/// let candidates = extract_from_ocr(text);
+/// assert_eq!(candidates.len(), 1);
+/// assert_eq!(candidates[0].sin.digits_string(), "046454286");
+/// assert!(candidates[0].anchored);
+/// assert_eq!(candidates[0].substitutions.len(), 1);
+/// ```
+pub fn extract_from_ocr(text: &str) -> Vec<SINCandidate> {
+    let mut candidates = Vec::new();
+    for group in token_groups(text) {
+        for first in 0..group.len() {
+            let mut digits = Vec::new();
+            for last in first..group.len() {
+                digits.extend_from_slice(&group[last].digits);
+                if digits.len() > 9 {
+                    break;
+                }
+                if digits.len() < 9 {
+                    continue;
+                }
+                let sin = match SIN::from_digit_vec(digits.clone()) {
+                    Ok(sin) => sin,
+                    Err(_) => break,
+                };
+                let tokens = &group[first..=last];
+                let start = tokens[0].start;
+                let anchored = is_anchored(text, start);
+                let substitutions: Vec<OcrSubstitution> = tokens
+                    .iter()
+                    .flat_map(|token| token.substitutions.iter().copied())
+                    .collect();
+                let sizes: Vec<usize> = tokens.iter().map(|token| token.digits.len()).collect();
+                let line_breaks = tokens[1..]
+                    .iter()
+                    .filter(|token| token.after_line_break)
+                    .count();
+                let mut confidence = 0.5;
+                if anchored {
+                    confidence += 0.3;
+                }
+                if sizes == [9] || sizes == [3, 3, 3] {
+                    confidence += 0.1;
+                }
+                confidence -= 0.1 * (substitutions.len() + line_breaks) as f32;
+                candidates.push(SINCandidate {
+                    sin,
+                    start,
+                    end: tokens[tokens.len() - 1].end,
+                    substitutions,
+                    anchored,
+                    confidence: f32::clamp(confidence, 0.0, 1.0),
+                });
+                break;
+            }
+        }
+    }
+    candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    let mut kept: Vec<SINCandidate> = Vec::new();
+    for candidate in candidates {
+        if kept
+            .iter()
+            .all(|other| candidate.end <= other.start || other.end <= candidate.start)
+        {
+            kept.push(candidate);
+        }
+    }
+    kept.sort_by_key(|candidate| candidate.start);
+    kept
+}
+
+/// The digit that OCR commonly mistakes `khar` for.
+fn confused_digit(khar: char) -> Option<u8> {
+    match khar {
+        'O' | 'o' | 'D' | 'Q' => Some(0),
+        'I' | 'l' | 'i' | '|' => Some(1),
+        'Z' | 'z' => Some(2),
+        'S' | 's' => Some(5),
+        'G' | 'b' => Some(6),
+        'B' => Some(8),
+        'g' | 'q' => Some(9),
+        _ => None,
+    }
+}
+
+impl SIN {
+    /// Parses a SIN from text produced by OCR, where digits are often read as similar-looking
+    /// letters, such as `O` for `0`, `l` or `I` for `1`, and `S` for `5`. Those letters are
+    /// replaced with digits before validation, and the replacements are returned along with the
+    /// SIN so they can be reviewed. Other characters are ignored, like in [`SIN::parse`].
+    ///
+    /// This accepts more mistakes than [`SIN::parse`], so it should only be used on OCR output.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{OcrSubstitution, SIN};
+    /// let (sin, substitutions) = SIN::parse_ocr("O46-4S4-286".to_string()).unwrap();
+    /// assert_eq!(sin.digits_string(), "046454286");
+    /// assert_eq!(
+    ///     substitutions,
+    ///     vec![
+    ///         OcrSubstitution { position: 0, found: 'O', digit: 0 },
+    ///         OcrSubstitution { position: 5, found: 'S', digit: 5 },
+    ///     ],
+    /// );
+    /// ```
+    pub fn parse_ocr(s: impl AsRef<str>) -> Result<(Self, Vec<OcrSubstitution>), SINParseError> {
+        let mut digits = Vec::with_capacity(9);
+        let mut substitutions = Vec::new();
+        for (position, khar) in s.as_ref().chars().enumerate() {
+            if let Some(digit) = khar.to_digit(10) {
+                digits.push(digit as u8);
+            } else if let Some(digit) = confused_digit(khar) {
+                digits.push(digit);
+                substitutions.push(OcrSubstitution {
+                    position,
+                    found: khar,
+                    digit,
+                });
+            }
+        }
+        Ok((Self::from_digit_vec(digits)?, substitutions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn repairs() {
+        let (sin, substitutions) = SIN::parse_ocr("l3O 692 544".to_string()).unwrap();
+        assert_eq!(sin.digits_string(), "130692544");
+        assert_eq!(substitutions.len(), 2);
+        let (_, substitutions) = SIN::parse_ocr("046454286".to_string()).unwrap();
+        assert!(substitutions.is_empty());
+        assert_eq!(
+            SIN::parse_ocr("O46-4S4-287".to_string()),
+            Err(SINParseError::InvalidChecksum)
+        );
+        assert_eq!(
+            SIN::parse_ocr("SIN: 046454286".to_string()),
+            Err(SINParseError::TooLong)
+        );
+    }
+
+    #[test]
+    fn extracts_candidates() {
+        let text = "Numéro d'assurance sociale : 046-454-286\nNom : Doe\nRef 13O 692 544 0001";
+        let candidates = extract_from_ocr(text);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(&text[candidates[0].start..candidates[0].end], "046-454-286");
+        assert!(candidates[0].anchored);
+        assert!((candidates[0].confidence - 0.9).abs() < 1e-6);
+        assert_eq!(candidates[1].sin.digits_string(), "130692544");
+        assert_eq!(candidates[1].substitutions[0].position, 57);
+        assert!(!candidates[1].anchored);
+        assert!(extract_from_ocr("SIN: 046454287").is_empty());
+        assert!(extract_from_ocr("0464542860").is_empty());
+    }
+}
@@ -0,0 +1,167 @@
+//! Stricter parsing, where only specific separators are allowed between digits.
+
+use crate::{SINParseError, SIN};
+
+/// The separators allowed by default: space, no-break space, hyphen, period and middle dot.
+pub(crate) const DEFAULT_SEPARATORS: [char; 5] = [' ', '\u{a0}', '-', '.', '·'];
+
+/// Options for [`SIN::parse_with`], which controls which separators are allowed between digits.
+/// Unlike [`SIN::parse`], which ignores every character that isn't a digit, any character that
+/// isn't a digit or an allowed separator is rejected.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{ParseOptions, SIN, SINParseError};
+/// let options = ParseOptions::new();
+/// assert!(SIN::parse_with("046 454 286".to_string(), &options).is_ok());
+/// assert!(SIN::parse_with("046.454.286".to_string(), &options).is_ok());
+/// assert_eq!(
+///     SIN::parse_with("046/454/286".to_string(), &options),
+///     Err(SINParseError::InvalidCharacter),
+/// );
+///
+/// let options = ParseOptions::new().with_separators(&['-']).require_group_positions();
+/// assert!(SIN::parse_with("046-454-286".to_string(), &options).is_ok());
+/// assert!(SIN::parse_with("046454286".to_string(), &options).is_ok());
+/// assert_eq!(
+///     SIN::parse_with("0464-54-286".to_string(), &options),
+///     Err(SINParseError::MisplacedSeparator),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    separators: Vec<char>,
+    require_group_positions: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            separators: DEFAULT_SEPARATORS.to_vec(),
+            require_group_positions: false,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Creates options that allow spaces, no-break spaces, hyphens, periods and middle dots as
+    /// separators, anywhere in the SIN.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Replaces the set of allowed separators.
+    pub fn with_separators(mut self, separators: &[char]) -> Self {
+        self.separators = separators.to_vec();
+        self
+    }
+    /// Only allows a single separator between the 3rd and 4th digits, and between the 6th and 7th
+    /// digits, like in `046-454-286`.
+    pub fn require_group_positions(mut self) -> Self {
+        self.require_group_positions = true;
+        self
+    }
+}
+
+impl SIN {
+    /// Parses a SIN from a string, only allowing the separators given in `options`. Whitespace at
+    /// the start and end of the string is ignored.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{ParseOptions, SIN};
+    /// let sin = SIN::parse_with(" 046\u{a0}454\u{a0}286 ".to_string(), &ParseOptions::new()).unwrap();
+    /// assert_eq!(sin.digits_string(), "046454286");
+    /// ```
+    pub fn parse_with(s: impl AsRef<str>, options: &ParseOptions) -> Result<Self, SINParseError> {
+        let mut digits = Vec::with_capacity(9);
+        let mut last_was_separator = false;
+        for khar in s.as_ref().trim().chars() {
+            if let Some(digit) = khar.to_digit(10) {
+                digits.push(digit as u8);
+                last_was_separator = false;
+            } else if options.separators.contains(&khar) {
+                if options.require_group_positions
+                    && (last_was_separator || (digits.len() != 3 && digits.len() != 6))
+                {
+                    return Err(SINParseError::MisplacedSeparator);
+                }
+                last_was_separator = true;
+            } else {
+                return Err(SINParseError::InvalidCharacter);
+            }
+        }
+        Self::from_digit_vec(digits)
+    }
+    /// Removes whitespace and separators from a SIN, returning only its digits, without checking
+    /// the length or checksum. This is for handing SINs to systems that do their own validation,
+    /// but expect bare digits. Any character other than a digit, whitespace or one of the
+    /// separators allowed by [`ParseOptions::new`] is an error.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{SINParseError, SIN};
+    /// assert_eq!(SIN::canonicalize(" 046-454-286\n"), Ok("046454286".to_string()));
+    /// assert_eq!(SIN::canonicalize("046 454 2"), Ok("0464542".to_string()));
+    /// assert_eq!(SIN::canonicalize("046/454/286"), Err(SINParseError::InvalidCharacter));
+    /// ```
+    pub fn canonicalize(s: &str) -> Result<String, SINParseError> {
+        let mut digits = String::with_capacity(9);
+        for khar in s.chars() {
+            if khar.is_ascii_digit() {
+                digits.push(khar);
+            } else if !khar.is_whitespace() && !DEFAULT_SEPARATORS.contains(&khar) {
+                return Err(SINParseError::InvalidCharacter);
+            }
+        }
+        Ok(digits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn group_positions() {
+        let options = ParseOptions::new().require_group_positions();
+        assert!(SIN::parse_with("046 454-286".to_string(), &options).is_ok());
+        assert!(SIN::parse_with("046454 286".to_string(), &options).is_ok());
+        assert_eq!(
+            SIN::parse_with("046--454286".to_string(), &options),
+            Err(SINParseError::MisplacedSeparator)
+        );
+        assert_eq!(
+            SIN::parse_with("-046454286".to_string(), &options),
+            Err(SINParseError::MisplacedSeparator)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn custom_separators() {
+        let options = ParseOptions::new().with_separators(&[]);
+        assert!(SIN::parse_with("046454286".to_string(), &options).is_ok());
+        assert_eq!(
+            SIN::parse_with("046 454 286".to_string(), &options),
+            Err(SINParseError::InvalidCharacter)
+        );
+        assert_eq!(
+            SIN::parse_with("04645428".to_string(), &options),
+            Err(SINParseError::TooShort)
+        );
+    }
+
+    #[test]
+    fn canonicalize_keeps_only_digits() {
+        assert_eq!(
+            SIN::canonicalize("\t046\u{a0}454·286"),
+            Ok("046454286".to_string())
+        );
+        assert_eq!(SIN::canonicalize(""), Ok(String::new()));
+        assert_eq!(
+            SIN::canonicalize("٠٤٦٤٥٤٢٨٦"),
+            Err(SINParseError::InvalidCharacter)
+        );
+    }
+}
@@ -0,0 +1,162 @@
+//! SINs that are still being entered.
+
+use crate::{luhn, SINParseError, SINType, SIN};
+
+/// A SIN that is still being entered, one digit at a time, with up to 9 digits.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{PartialSIN, SINParseError};
+/// let mut partial = PartialSIN::new();
+/// for digit in [0, 4, 6, 4, 5, 4, 2, 8] {
+///     partial.push_digit(digit).unwrap();
+/// }
+/// assert!(!partial.is_complete());
+/// assert_eq!(partial.clone().finish(), Err(SINParseError::TooShort));
+/// partial.push_digit(6).unwrap();
+/// assert!(partial.is_complete());
+/// assert_eq!(partial.finish().unwrap().digits_string(), "046454286");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartialSIN {
+    digits: [u8; 9],
+    len: usize,
+}
+
+impl PartialSIN {
+    /// Creates a partial SIN with no digits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Adds a digit to the end. This fails if the digit is greater than 9, or if there are already
+    /// 9 digits.
+    pub fn push_digit(&mut self, digit: u8) -> Result<(), SINParseError> {
+        if digit > 9 {
+            return Err(SINParseError::InvalidDigit);
+        }
+        if self.is_complete() {
+            return Err(SINParseError::TooLong);
+        }
+        self.digits[self.len] = digit;
+        self.len += 1;
+        Ok(())
+    }
+    /// Removes the last digit, if there is one.
+    pub fn pop_digit(&mut self) -> Option<u8> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.digits[self.len])
+    }
+    /// The digits entered so far.
+    pub fn digits(&self) -> &[u8] {
+        &self.digits[..self.len]
+    }
+    /// The number of digits entered so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Are there no digits yet?
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Have all 9 digits been entered?
+    pub fn is_complete(&self) -> bool {
+        self.len == 9
+    }
+    /// Can more digits be added to make a valid SIN of type `ty` (or any type, if `ty` is `None`)?
+    /// If all 9 digits have been entered, this checks if they are a valid SIN.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{PartialSIN, SINType};
+    /// let mut partial = PartialSIN::new();
+    /// partial.push_digit(9).unwrap();
+    /// assert!(partial.can_complete(None));
+    /// assert!(partial.can_complete(Some(SINType::TemporaryResident)));
+    /// assert!(!partial.can_complete(Some(SINType::Ontario)));
+    /// ```
+    pub fn can_complete(&self, ty: Option<SINType>) -> bool {
+        is_valid_prefix(self.digits(), ty)
+    }
+    /// Turns the digits into a SIN, failing if there aren't 9 digits or the checksum is invalid.
+    pub fn finish(self) -> Result<SIN, SINParseError> {
+        if !self.is_complete() {
+            return Err(SINParseError::TooShort);
+        }
+        if !luhn::is_valid(&self.digits) {
+            return Err(SINParseError::InvalidChecksum);
+        }
+        Ok(SIN::from_valid_digits(self.digits))
+    }
+}
+
+impl SIN {
+    /// Can the digits in `s` be extended to make a valid SIN of type `ty` (or any type, if `ty` is
+    /// `None`)? This is useful for flagging impossible input while it is being typed. Characters
+    /// that aren't digits are ignored, like in [`SIN::parse`].
+    ///
+    /// Any 8 digits can be made into a valid SIN by adding a check digit, so the only things that
+    /// can make a prefix invalid are having too many digits, a first digit that doesn't match the
+    /// type, or 9 digits with an invalid checksum.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{SIN, SINType};
+    /// assert!(SIN::is_valid_prefix("046-45".to_string(), None));
+    /// assert!(SIN::is_valid_prefix("046-45".to_string(), Some(SINType::CRAAssigned)));
+    /// assert!(!SIN::is_valid_prefix("046-45".to_string(), Some(SINType::Quebec)));
+    /// assert!(!SIN::is_valid_prefix("046-454-287".to_string(), None));
+    /// assert!(!SIN::is_valid_prefix("046-454-286-1".to_string(), None));
+    /// ```
+    pub fn is_valid_prefix(s: impl AsRef<str>, ty: Option<SINType>) -> bool {
+        is_valid_prefix(&Self::extract_digits(s.as_ref()), ty)
+    }
+}
+
+fn is_valid_prefix(digits: &[u8], ty: Option<SINType>) -> bool {
+    if digits.len() > 9 {
+        return false;
+    }
+    if let (Some(ty), Some(&first)) = (ty, digits.first()) {
+        if !SIN::types_for_first_digit(first).contains(&ty) {
+            return false;
+        }
+    }
+    digits.len() < 9 || luhn::is_valid(digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_and_pop() {
+        let mut partial = PartialSIN::new();
+        assert!(partial.is_empty());
+        assert_eq!(partial.push_digit(10), Err(SINParseError::InvalidDigit));
+        for _ in 0..9 {
+            partial.push_digit(9).unwrap();
+        }
+        assert_eq!(partial.push_digit(0), Err(SINParseError::TooLong));
+        assert_eq!(
+            partial.clone().finish(),
+            Err(SINParseError::InvalidChecksum)
+        );
+        assert_eq!(partial.pop_digit(), Some(9));
+        partial.push_digit(8).unwrap();
+        assert_eq!(partial.digits(), &[9, 9, 9, 9, 9, 9, 9, 9, 8]);
+        assert!(partial.finish().is_ok());
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn valid_prefixes() {
+        assert!(SIN::is_valid_prefix("".to_string(), Some(SINType::Yukon)));
+        assert!(SIN::is_valid_prefix("7".to_string(), Some(SINType::Yukon)));
+        assert!(SIN::is_valid_prefix("04645428".to_string(), None));
+        assert!(SIN::is_valid_prefix("046454286".to_string(), None));
+        assert!(!SIN::is_valid_prefix("8".to_string(), Some(SINType::Yukon)));
+    }
+}
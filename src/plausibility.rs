@@ -0,0 +1,86 @@
+//! Heuristics for SINs that have a valid checksum, but are unlikely to have been issued.
+
+use crate::SIN;
+
+/// The shortest run of consecutive digits that is considered suspicious.
+const MIN_RUN: usize = 6;
+
+/// Patterns found in a SIN that suggest it was made up, as returned by [`SIN::plausibility`].
+/// Real SINs can match these patterns too, so this is a signal for review, not proof of fraud.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct Plausibility {
+    /// The first 8 digits are all the same, like `111-111-118`. The check digit is ignored, since
+    /// it usually breaks the pattern.
+    pub identical_digits: bool,
+    /// There is a run of at least 6 ascending digits, like the `345678` in `934-567-8xx`.
+    pub ascending_run: bool,
+    /// There is a run of at least 6 descending digits, like the `876543` in `987-654-3xx`.
+    pub descending_run: bool,
+    /// At least two of the three groups of 3 digits are the same, like `456-456-xxx`.
+    pub repeated_triplets: bool,
+}
+
+impl Plausibility {
+    /// Were no suspicious patterns found?
+    pub fn is_plausible(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl SIN {
+    /// Looks for patterns that suggest the SIN was made up, such as repeated or sequential
+    /// digits. These SINs pass the checksum, but are unlikely to belong to a real person.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// let sin = SIN::parse("123-456-782".to_string()).unwrap();
+    /// let plausibility = sin.plausibility();
+    /// assert!(plausibility.ascending_run);
+    /// assert!(!plausibility.is_plausible());
+    /// assert!(SIN::parse("046-454-286".to_string()).unwrap().plausibility().is_plausible());
+    /// ```
+    pub fn plausibility(&self) -> Plausibility {
+        let digits = self.digits();
+        let run = |step: i8| {
+            let mut len = 1;
+            let mut longest = 1;
+            for pair in digits.windows(2) {
+                if pair[1] as i8 - pair[0] as i8 == step {
+                    len += 1;
+                    longest = longest.max(len);
+                } else {
+                    len = 1;
+                }
+            }
+            longest >= MIN_RUN
+        };
+        let (a, b, c) = (&digits[0..3], &digits[3..6], &digits[6..9]);
+        Plausibility {
+            identical_digits: digits[..8].iter().all(|&digit| digit == digits[0]),
+            ascending_run: run(1),
+            descending_run: run(-1),
+            repeated_triplets: a == b || b == c || a == c,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::unnecessary_to_owned)]
+    fn plausibility(s: &str) -> Plausibility {
+        SIN::parse(s.to_string()).unwrap().plausibility()
+    }
+
+    #[test]
+    fn patterns() {
+        let p = plausibility("111111118");
+        assert!(p.identical_digits && p.repeated_triplets);
+        assert!(!p.ascending_run && !p.descending_run);
+        assert!(plausibility("987654324").descending_run);
+        assert!(plausibility("046046041").repeated_triplets);
+        assert!(plausibility("946454287").is_plausible());
+    }
+}
@@ -0,0 +1,155 @@
+//! Rules about which valid SINs are acceptable, for consumers that need more than a valid
+//! checksum.
+
+use crate::{SINType, SIN};
+use std::{error::Error, fmt};
+
+/// A set of rules that a SIN must follow, checked with [`SIN::validate_with`]. The default policy
+/// accepts every SIN, and rules are added with the builder methods.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{PolicyViolation, SIN, SINType, ValidationPolicy};
+/// let policy = ValidationPolicy::new()
+///     .reject_business_numbers()
+///     .reject_temporary_residents()
+///     .require_any_type(&[SINType::Ontario, SINType::Quebec]);
+/// let sin = SIN::parse("046454286".to_string()).unwrap();
+/// assert_eq!(sin.validate_with(&policy), Err(PolicyViolation::TypeNotAllowed));
+/// let sin = SIN::parse("346454382".to_string()).unwrap();
+/// assert_eq!(sin.validate_with(&policy), Ok(()));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationPolicy {
+    reject_business_numbers: bool,
+    reject_temporary_residents: bool,
+    reject_placeholders: bool,
+    required_types: Option<Vec<SINType>>,
+}
+
+impl ValidationPolicy {
+    /// Creates a policy that accepts every SIN.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Rejects SINs that can only be business numbers (those starting with 8).
+    pub fn reject_business_numbers(mut self) -> Self {
+        self.reject_business_numbers = true;
+        self
+    }
+    /// Rejects SINs given to temporary residents (those starting with 9).
+    pub fn reject_temporary_residents(mut self) -> Self {
+        self.reject_temporary_residents = true;
+        self
+    }
+    /// Rejects placeholder values (see [`SIN::is_placeholder`]).
+    pub fn reject_placeholders(mut self) -> Self {
+        self.reject_placeholders = true;
+        self
+    }
+    /// Requires that at least one of the types the SIN could be is in `types`. Calling this again
+    /// adds more allowed types.
+    pub fn require_any_type(mut self, types: &[SINType]) -> Self {
+        self.required_types
+            .get_or_insert_with(Vec::new)
+            .extend_from_slice(types);
+        self
+    }
+}
+
+/// The rule of a [`ValidationPolicy`] that a SIN broke.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PolicyViolation {
+    /// The SIN is a business number, and business numbers are rejected.
+    BusinessNumber,
+    /// The SIN was given to a temporary resident, and temporary residents are rejected.
+    TemporaryResident,
+    /// The SIN is a placeholder value, and placeholders are rejected.
+    Placeholder,
+    /// None of the types the SIN could be are allowed.
+    TypeNotAllowed,
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::BusinessNumber => "business numbers are not allowed",
+            Self::TemporaryResident => "temporary resident SINs are not allowed",
+            Self::Placeholder => "placeholder SINs are not allowed",
+            Self::TypeNotAllowed => "SIN is not of an allowed type",
+        })
+    }
+}
+
+impl Error for PolicyViolation {}
+
+impl SIN {
+    /// Checks the SIN against the rules of a policy, returning the first rule that was broken.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{PolicyViolation, SIN, ValidationPolicy};
+    /// let policy = ValidationPolicy::new().reject_placeholders();
+    /// let sin = SIN::parse("000000000".to_string()).unwrap();
+    /// assert_eq!(sin.validate_with(&policy), Err(PolicyViolation::Placeholder));
+    /// ```
+    pub fn validate_with(&self, policy: &ValidationPolicy) -> Result<(), PolicyViolation> {
+        if policy.reject_placeholders && self.is_placeholder() {
+            return Err(PolicyViolation::Placeholder);
+        }
+        if policy.reject_business_numbers && self.first_digit() == 8 {
+            return Err(PolicyViolation::BusinessNumber);
+        }
+        if policy.reject_temporary_residents && self.first_digit() == 9 {
+            return Err(PolicyViolation::TemporaryResident);
+        }
+        if let Some(required_types) = &policy.required_types {
+            if !self.types().iter().any(|ty| required_types.contains(ty)) {
+                return Err(PolicyViolation::TypeNotAllowed);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::unnecessary_to_owned)]
+    fn sin(s: &str) -> SIN {
+        SIN::parse(s.to_string()).unwrap()
+    }
+
+    #[test]
+    fn default_accepts_everything() {
+        let policy = ValidationPolicy::new();
+        for s in ["000000000", "046454286", "800000002", "999999998"] {
+            assert_eq!(sin(s).validate_with(&policy), Ok(()));
+        }
+    }
+
+    #[test]
+    fn rules_are_checked() {
+        let policy = ValidationPolicy::new()
+            .reject_business_numbers()
+            .reject_temporary_residents();
+        assert_eq!(
+            sin("800000002").validate_with(&policy),
+            Err(PolicyViolation::BusinessNumber)
+        );
+        assert_eq!(
+            sin("999999998").validate_with(&policy),
+            Err(PolicyViolation::TemporaryResident)
+        );
+        // starting with 7 might not be a business number
+        assert_eq!(sin("734323843").validate_with(&policy), Ok(()));
+        let policy = policy.require_any_type(&[SINType::Yukon]);
+        assert_eq!(sin("734323843").validate_with(&policy), Ok(()));
+        assert_eq!(
+            sin("046454286").validate_with(&policy),
+            Err(PolicyViolation::TypeNotAllowed)
+        );
+    }
+}
@@ -0,0 +1,110 @@
+//! Federal Personal Record Identifiers.
+
+use crate::{luhn, SIN};
+use std::{convert::TryInto, error::Error, fmt, str::FromStr};
+
+/// An error resulting from parsing a PRI.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PRIParseError {
+    /// The PRI is longer than 9 digits.
+    TooLong,
+    /// The PRI is less than 8 digits.
+    TooShort,
+    /// The PRI has an invalid check digit.
+    InvalidChecksum,
+}
+
+impl fmt::Display for PRIParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::TooLong => "PRI is longer than 9 digits",
+            Self::TooShort => "PRI is less than 8 digits",
+            Self::InvalidChecksum => "PRI has an invalid check digit",
+        })
+    }
+}
+
+impl Error for PRIParseError {}
+
+/// A Personal Record Identifier (PRI), which the Government of Canada uses to identify its
+/// employees. PRIs have 8 or 9 digits, where the last digit is a Luhn check digit. 8-digit PRIs
+/// are the same as 9-digit PRIs with a leading zero.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::PRI;
+/// let pri = PRI::parse("12345674".to_string()).unwrap();
+/// assert_eq!(pri.to_string(), "12345674");
+/// assert_eq!(pri, PRI::parse("012345674".to_string()).unwrap());
+/// assert!(PRI::parse("12345675".to_string()).is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct PRI {
+    digits: [u8; 9],
+}
+
+impl PRI {
+    /// Parses a PRI. Characters that aren't digits are ignored.
+    pub fn parse(s: String) -> Result<Self, PRIParseError> {
+        let mut digits = SIN::extract_digits(&s);
+        match digits.len() {
+            n if n < 8 => return Err(PRIParseError::TooShort),
+            n if n > 9 => return Err(PRIParseError::TooLong),
+            8 => digits.insert(0, 0),
+            _ => {}
+        }
+        if !luhn::is_valid(&digits) {
+            return Err(PRIParseError::InvalidChecksum);
+        }
+        Ok(Self {
+            digits: digits.try_into().unwrap(),
+        })
+    }
+    /// Returns the PRI as 9 digits, with a leading zero for 8-digit PRIs.
+    pub fn digits(self) -> [u8; 9] {
+        self.digits
+    }
+}
+
+impl FromStr for PRI {
+    type Err = PRIParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.to_string())
+    }
+}
+
+impl fmt::Display for PRI {
+    /// Formats the PRI as digits, without a leading zero.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let start = if self.digits[0] == 0 { 1 } else { 0 };
+        for digit in &self.digits[start..] {
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pri() {
+        let pri: PRI = "123 456 782".parse().unwrap();
+        assert_eq!(pri.to_string(), "123456782");
+        assert_eq!(
+            PRI::parse("1234567".to_string()),
+            Err(PRIParseError::TooShort)
+        );
+        assert_eq!(
+            PRI::parse("1234567820".to_string()),
+            Err(PRIParseError::TooLong)
+        );
+        assert_eq!(
+            PRI::parse("123456783".to_string()),
+            Err(PRIParseError::InvalidChecksum)
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Replacing real SINs with stable fake ones, so datasets can be shared without real numbers.
+
+use crate::{siphash, SIN, SINS_PER_FIRST_DIGIT};
+use std::fmt;
+
+/// The first 8 digits are split into two halves of this size for the Feistel network.
+const HALF: u32 = 10_000;
+/// The number of Feistel rounds.
+const ROUNDS: u8 = 8;
+
+/// Maps real SINs to fake SINs, using a secret key. The same SIN and key always give the same
+/// fake SIN, and different SINs always give different fake SINs, so the fake SINs can still be
+/// used to join datasets. Fake SINs have a valid checksum, and can't be turned back into the
+/// real SINs without the key.
+///
+/// Since there are only 100 million valid SINs, the key must be kept secret: anyone with the key
+/// can pseudonymize every SIN and build a reverse lookup table. Use a random key, and use
+/// different keys for datasets that shouldn't be joinable.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{Pseudonymizer, SIN};
+/// let sin = SIN::parse("046454286".to_string()).unwrap();
+/// let pseudonymizer = Pseudonymizer::new(*b"a very secret k!");
+/// let fake = pseudonymizer.pseudonymize(sin);
+/// assert_ne!(fake, sin);
+/// assert_eq!(pseudonymizer.pseudonymize(sin), fake);
+///
+/// let fake = pseudonymizer.preserve_first_digit().pseudonymize(sin);
+/// assert_eq!(fake.digits()[0], 0);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Pseudonymizer {
+    key: [u8; 16],
+    preserve_first_digit: bool,
+}
+
+impl Pseudonymizer {
+    /// Creates a pseudonymizer with a 128-bit secret key. Fake SINs can start with any digit.
+    pub fn new(key: [u8; 16]) -> Self {
+        Self {
+            key,
+            preserve_first_digit: false,
+        }
+    }
+    /// Keeps the first digit of each SIN, so fake SINs have the same [`SIN::types`] as the real
+    /// ones. This reveals the types of the real SINs.
+    pub fn preserve_first_digit(mut self) -> Self {
+        self.preserve_first_digit = true;
+        self
+    }
+    /// Returns the fake SIN for `sin`.
+    pub fn pseudonymize(&self, sin: SIN) -> SIN {
+        let body = sin.body_number();
+        if !self.preserve_first_digit {
+            return SIN::from_body_number(self.permute(body));
+        }
+        // cycle-walk until the result is back within the block of numbers that start with the
+        // same digit, which keeps the mapping one-to-one
+        let first = body / SINS_PER_FIRST_DIGIT;
+        let mut rest = body % SINS_PER_FIRST_DIGIT;
+        loop {
+            rest = self.permute(rest);
+            if rest < SINS_PER_FIRST_DIGIT {
+                return SIN::from_body_number(first * SINS_PER_FIRST_DIGIT + rest);
+            }
+        }
+    }
+    /// A keyed permutation of the numbers below 100000000 (one for each valid SIN), built from a
+    /// Feistel network.
+    fn permute(&self, number: u32) -> u32 {
+        let (mut left, mut right) = (number / HALF, number % HALF);
+        for round in 0..ROUNDS {
+            let mut data = [0; 5];
+            data[0] = round;
+            data[1..].copy_from_slice(&right.to_le_bytes());
+            let f = (siphash::hash(&self.key, &data) % u64::from(HALF)) as u32;
+            let next = (left + f) % HALF;
+            left = right;
+            right = next;
+        }
+        left * HALF + right
+    }
+}
+
+impl fmt::Debug for Pseudonymizer {
+    /// Formats the pseudonymizer without the key.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pseudonymizer")
+            .field("preserve_first_digit", &self.preserve_first_digit)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SINRange;
+
+    #[test]
+    fn one_to_one() {
+        let pseudonymizer = Pseudonymizer::new([7; 16]).preserve_first_digit();
+        let start = SIN::from_body_number(12_340_000);
+        let end = SIN::from_body_number(12_349_999);
+        let mut fakes: Vec<SIN> = SINRange::new(start, end)
+            .iter()
+            .map(|sin| pseudonymizer.pseudonymize(sin))
+            .collect();
+        assert!(fakes.iter().all(|fake| fake.digits()[0] == 1));
+        fakes.sort();
+        fakes.dedup();
+        assert_eq!(fakes.len(), 10_000);
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn keys_differ() {
+        let sin = SIN::parse("046454286".to_string()).unwrap();
+        assert_ne!(
+            Pseudonymizer::new([1; 16]).pseudonymize(sin),
+            Pseudonymizer::new([2; 16]).pseudonymize(sin)
+        );
+        assert_eq!(
+            format!("{:?}", Pseudonymizer::new([1; 16])),
+            "Pseudonymizer { preserve_first_digit: false, .. }"
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Québec sales tax registration numbers.
+
+use crate::{cra::check_length, AccountNumberParseError, SIN};
+use std::{convert::TryInto, fmt, str::FromStr};
+
+/// A Québec sales tax (QST) registration number, assigned by Revenu Québec. It is 10 digits, the
+/// program identifier `TQ`, and a 4-digit reference number, like `1234567890TQ0001`. These are
+/// separate from GST/HST numbers, which are based on federal business numbers.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{AccountNumberParseError, QSTNumber};
+/// let number = QSTNumber::parse("1234567890 TQ 0001".to_string()).unwrap();
+/// assert_eq!(number.reference(), 1);
+/// assert_eq!(number.to_string(), "1234567890TQ0001");
+/// assert_eq!(number.spaced_string(), "1234567890 TQ 0001");
+/// assert_eq!(
+///     QSTNumber::parse("1234567890RT0001".to_string()),
+///     Err(AccountNumberParseError::InvalidProgram),
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct QSTNumber {
+    digits: [u8; 10],
+    reference: u16,
+}
+
+impl QSTNumber {
+    /// Parses a QST registration number. Spaces and dashes are ignored, and the program
+    /// identifier can be in either case.
+    pub fn parse(s: String) -> Result<Self, AccountNumberParseError> {
+        let s: String = s
+            .chars()
+            .filter(|&c| c != ' ' && c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (digits, rest) = s.split_at(split);
+        let digits = SIN::extract_digits(digits);
+        check_length(&digits, 10)?;
+        let reference = rest
+            .strip_prefix("TQ")
+            .ok_or(AccountNumberParseError::InvalidProgram)?;
+        if !reference.chars().all(|c| c.is_ascii_digit()) {
+            return Err(AccountNumberParseError::InvalidCharacter);
+        }
+        let reference = SIN::extract_digits(reference);
+        check_length(&reference, 4)?;
+        let reference = reference
+            .iter()
+            .fold(0, |acc, &digit| acc * 10 + u16::from(digit));
+        if reference == 0 {
+            return Err(AccountNumberParseError::InvalidReference);
+        }
+        Ok(Self {
+            digits: digits.try_into().unwrap(),
+            reference,
+        })
+    }
+    /// Returns the 10 digits before the program identifier.
+    pub fn digits(self) -> [u8; 10] {
+        self.digits
+    }
+    /// The reference number, from 1 to 9999.
+    pub fn reference(self) -> u16 {
+        self.reference
+    }
+    /// Returns the number with spaces around the program identifier, like `1234567890 TQ 0001`.
+    pub fn spaced_string(self) -> String {
+        format!(
+            "{} TQ {:04}",
+            self.digits
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<String>(),
+            self.reference
+        )
+    }
+}
+
+impl FromStr for QSTNumber {
+    type Err = AccountNumberParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.to_string())
+    }
+}
+
+impl fmt::Display for QSTNumber {
+    /// Formats the number like `1234567890TQ0001`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for digit in &self.digits {
+            write!(f, "{}", digit)?;
+        }
+        write!(f, "TQ{:04}", self.reference)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_qst() {
+        let number: QSTNumber = "1234567890-tq-9999".parse().unwrap();
+        assert_eq!(number.reference(), 9999);
+        assert_eq!(
+            QSTNumber::parse("1234567890TQ0000".to_string()),
+            Err(AccountNumberParseError::InvalidReference)
+        );
+        assert_eq!(
+            QSTNumber::parse("123456789TQ0001".to_string()),
+            Err(AccountNumberParseError::TooShort)
+        );
+        assert_eq!(
+            QSTNumber::parse("1234567890TQ00011".to_string()),
+            Err(AccountNumberParseError::TooLong)
+        );
+        assert_eq!(
+            QSTNumber::parse("1234567890".to_string()),
+            Err(AccountNumberParseError::InvalidProgram)
+        );
+        assert_eq!(
+            QSTNumber::parse("1234567890TQ00é1".to_string()),
+            Err(AccountNumberParseError::InvalidCharacter)
+        );
+    }
+}
@@ -0,0 +1,40 @@
+//! Generating random SINs with the `rand` crate.
+
+use crate::{SIN, VALID_SINS};
+use ::rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+impl Distribution<SIN> for Standard {
+    /// Picks a SIN uniformly from every SIN with a valid checksum. The SIN may be a placeholder,
+    /// and may not have been issued.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SIN;
+    /// use rand::{distributions::Standard, Rng};
+    /// let sin: SIN = rand::thread_rng().gen();
+    /// assert!(SIN::parse(sin.digits_string()).is_ok());
+    /// let sins: Vec<SIN> = rand::thread_rng().sample_iter(Standard).take(5).collect();
+    /// assert_eq!(sins.len(), 5);
+    /// ```
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> SIN {
+        SIN::from_body_number(rng.gen_range(0..VALID_SINS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn random_sins_are_valid() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let sin: SIN = rng.gen();
+            assert_eq!(SIN::parse(sin.digits_string()), Ok(sin));
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Ranges of SINs.
+
+use crate::SIN;
+use std::convert::TryInto;
+
+/// An inclusive range of SINs, in numerical order. Since only 1 in 10 numbers has a valid checksum,
+/// a range only contains about a tenth of the numbers between its ends.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{SINRange, SIN};
+/// let range = SINRange::new(
+///     SIN::parse("046-454-286".to_string()).unwrap(),
+///     SIN::parse("046-454-385".to_string()).unwrap(),
+/// );
+/// assert_eq!(range.len(), 11);
+/// assert!(range.contains(&SIN::parse("046-454-294".to_string()).unwrap()));
+/// assert!(!range.contains(&SIN::parse("046-454-393".to_string()).unwrap()));
+/// assert_eq!(
+///     range.iter().nth(1).unwrap().digits_string(),
+///     "046454294",
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SINRange {
+    start: SIN,
+    end: SIN,
+}
+
+impl SINRange {
+    /// Creates a range from `start` to `end`, including both. If `start` is greater than `end`, the
+    /// range is empty.
+    pub fn new(start: SIN, end: SIN) -> Self {
+        Self { start, end }
+    }
+    /// The first SIN in the range.
+    pub fn start(&self) -> SIN {
+        self.start
+    }
+    /// The last SIN in the range.
+    pub fn end(&self) -> SIN {
+        self.end
+    }
+    /// Is `sin` in the range?
+    pub fn contains(&self, sin: &SIN) -> bool {
+        &self.start <= sin && sin <= &self.end
+    }
+    /// The number of valid SINs in the range.
+    pub fn len(&self) -> usize {
+        self.iter().len()
+    }
+    /// Are there no SINs in the range?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Iterates over every valid SIN in the range, in order.
+    pub fn iter(&self) -> SINRangeIter {
+        SINRangeIter {
+            next: self.start.body_number(),
+            end: self.end.body_number(),
+        }
+    }
+}
+
+impl IntoIterator for SINRange {
+    type Item = SIN;
+    type IntoIter = SINRangeIter;
+
+    fn into_iter(self) -> SINRangeIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the SINs in a [`SINRange`].
+#[derive(Debug, Clone)]
+pub struct SINRangeIter {
+    next: u32,
+    end: u32,
+}
+
+impl Iterator for SINRangeIter {
+    type Item = SIN;
+
+    fn next(&mut self) -> Option<SIN> {
+        if self.next > self.end {
+            return None;
+        }
+        let sin = SIN::from_body_number(self.next);
+        self.next += 1;
+        Some(sin)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<SIN> {
+        let n: u32 = n.try_into().unwrap_or(u32::MAX);
+        self.next = self.next.saturating_add(n);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.end + 1).saturating_sub(self.next).try_into().unwrap();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for SINRangeIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::unnecessary_to_owned)]
+    fn sin(s: &str) -> SIN {
+        SIN::parse(s.to_string()).unwrap()
+    }
+
+    #[test]
+    fn whole_space() {
+        let range = SINRange::new(sin("000000000"), sin("999999998"));
+        assert_eq!(range.len(), 100_000_000);
+        assert_eq!(range.iter().nth(99_999_999), Some(sin("999999998")));
+        assert_eq!(range.iter().nth(100_000_000), None);
+    }
+
+    #[test]
+    fn empty_and_single() {
+        let range = SINRange::new(sin("999999998"), sin("000000000"));
+        assert!(range.is_empty());
+        assert_eq!(range.iter().next(), None);
+        let range = SINRange::new(sin("046454286"), sin("046454286"));
+        assert_eq!(
+            range.into_iter().collect::<Vec<_>>(),
+            vec![sin("046454286")]
+        );
+    }
+
+    #[test]
+    fn iterates_in_order() {
+        let range = SINRange::new(sin("000000000"), sin("000001008"));
+        let sins: Vec<SIN> = range.iter().collect();
+        assert_eq!(sins.len(), range.len());
+        assert!(sins.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(sins.iter().all(|s| range.contains(s)));
+        assert!(sins.iter().all(|s| SIN::parse(s.digits_string()).is_ok()));
+    }
+}
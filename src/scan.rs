@@ -0,0 +1,254 @@
+//! Finding SINs in free text, such as documents and logs.
+
+use crate::{luhn, SIN};
+use std::convert::TryInto;
+
+/// Keywords that often appear near SINs, in English and French.
+pub(crate) const DEFAULT_KEYWORDS: [&str; 5] = [
+    "sin",
+    "nas",
+    "social insurance",
+    "numéro d'assurance sociale",
+    "assurance sociale",
+];
+
+/// Does `context` contain `keyword` as a whole word? Both should already be lowercase.
+pub(crate) fn contains_keyword(context: &str, keyword: &str) -> bool {
+    context.match_indices(keyword).any(|(idx, _)| {
+        let before = context[..idx].chars().next_back();
+        let after = context[idx + keyword.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+/// A SIN found in text by [`Scanner::scan`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SINMatch {
+    /// The SIN that was found.
+    pub sin: SIN,
+    /// The byte offset where the SIN starts.
+    pub start: usize,
+    /// The byte offset just after the end of the SIN.
+    pub end: usize,
+    /// How likely the match is to be a SIN, from 0 to 1. A Luhn-valid run of 9 digits could also
+    /// be a phone number or an invoice ID, so matches are scored based on their formatting, the
+    /// characters around them, the distribution of their digits, and whether a keyword like "SIN"
+    /// is nearby.
+    pub confidence: f32,
+}
+
+/// Finds SINs in text. Both 9-digit runs like `046454286` and groups of 3 digits separated by a
+/// space, hyphen or period like `046-454-286` are found, as long as they aren't part of a longer
+/// number and have a valid checksum. Matches with a confidence below the threshold are skipped.
+///
+/// Matches near a keyword, such as "SIN" or "numéro d'assurance sociale", get a higher
+/// confidence. Keywords are matched as whole words, ignoring case, within a window of characters
+/// before or after the match. For sweeps where false positives are expensive, a keyword can be
+/// required with [`Scanner::require_keyword`].
+///
+/// ## Examples
+/// ```
+/// use canada_sin::Scanner;
+/// let text = "Employee 046-454-286 was paid invoice 130692544.";
+/// let matches = Scanner::new().scan(text);
+/// assert_eq!(matches.len(), 2);
+/// assert_eq!(&text[matches[0].start..matches[0].end], "046-454-286");
+/// assert!(matches[0].confidence > matches[1].confidence);
+///
+/// let matches = Scanner::new().with_threshold(0.7).scan(text);
+/// assert_eq!(matches.len(), 1);
+///
+/// let text = "NAS : 130692544, commande 193456787";
+/// let matches = Scanner::new().with_window(10).require_keyword().scan(text);
+/// assert_eq!(matches.len(), 1);
+/// assert_eq!(&text[matches[0].start..matches[0].end], "130692544");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scanner {
+    threshold: f32,
+    keywords: Vec<String>,
+    window: usize,
+    require_keyword: bool,
+}
+
+impl Default for Scanner {
+    fn default() -> Self {
+        Self {
+            threshold: 0.5,
+            keywords: DEFAULT_KEYWORDS.iter().map(|k| k.to_string()).collect(),
+            window: 40,
+            require_keyword: false,
+        }
+    }
+}
+
+impl Scanner {
+    /// Creates a scanner that skips matches with a confidence below 0.5, and looks for the
+    /// default English and French keywords within 40 characters of each match.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Skips matches with a confidence below `threshold`.
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+    /// Replaces the keywords that are looked for near matches.
+    pub fn with_keywords(mut self, keywords: &[&str]) -> Self {
+        self.keywords = keywords.iter().map(|k| k.to_lowercase()).collect();
+        self
+    }
+    /// Sets how many characters before and after a match are searched for keywords.
+    pub fn with_window(mut self, window: usize) -> Self {
+        self.window = window;
+        self
+    }
+    /// Skips matches that don't have a keyword nearby, regardless of their confidence.
+    pub fn require_keyword(mut self) -> Self {
+        self.require_keyword = true;
+        self
+    }
+    /// Finds all the SINs in `text`, in the order they appear.
+    pub fn scan(&self, text: &str) -> Vec<SINMatch> {
+        let bytes = text.as_bytes();
+        let mut matches = Vec::new();
+        let mut start = 0;
+        while start < bytes.len() {
+            let is_start =
+                bytes[start].is_ascii_digit() && (start == 0 || !bytes[start - 1].is_ascii_digit());
+            let candidate = if is_start {
+                Self::candidate(bytes, start)
+            } else {
+                None
+            };
+            if let Some((digits, end, grouped)) = candidate {
+                if luhn::is_valid(&digits) {
+                    let sin = SIN::from_valid_digits(digits);
+                    let has_keyword = self.has_keyword_near(text, start, end);
+                    let mut confidence = Self::confidence(text, sin, start, end, grouped);
+                    if has_keyword {
+                        confidence = f32::min(confidence + 0.3, 1.0);
+                    }
+                    if confidence >= self.threshold && (has_keyword || !self.require_keyword) {
+                        matches.push(SINMatch {
+                            sin,
+                            start,
+                            end,
+                            confidence,
+                        });
+                    }
+                    start = end;
+                    continue;
+                }
+            }
+            start += 1;
+        }
+        matches
+    }
+    /// Reads a SIN-shaped number starting at `start`, returning its digits, where it ends, and
+    /// whether the digits were split into groups of 3.
+    fn candidate(bytes: &[u8], start: usize) -> Option<([u8; 9], usize, bool)> {
+        let digit_at = |idx: usize| bytes.get(idx).filter(|b| b.is_ascii_digit());
+        let ends_number = |idx: usize| digit_at(idx).is_none();
+        let plain: Option<Vec<u8>> = (start..start + 9)
+            .map(|idx| digit_at(idx).map(|b| b - b'0'))
+            .collect();
+        if let Some(digits) = plain.filter(|_| ends_number(start + 9)) {
+            return Some((digits.try_into().unwrap(), start + 9, false));
+        }
+        let separator = *bytes.get(start + 3)?;
+        if ![b' ', b'-', b'.'].contains(&separator) || bytes.get(start + 7) != Some(&separator) {
+            return None;
+        }
+        let grouped: Option<Vec<u8>> = (0..11)
+            .filter(|&offset| offset != 3 && offset != 7)
+            .map(|offset| digit_at(start + offset).map(|b| b - b'0'))
+            .collect();
+        grouped
+            .filter(|_| ends_number(start + 11))
+            .map(|digits| (digits.try_into().unwrap(), start + 11, true))
+    }
+    /// Is one of the keywords within the window around the match?
+    fn has_keyword_near(&self, text: &str, start: usize, end: usize) -> bool {
+        let before: String = text[..start].chars().rev().take(self.window).collect();
+        let before: String = before.chars().rev().collect();
+        let after: String = text[end..].chars().take(self.window).collect();
+        [before, after].iter().any(|context| {
+            let context = context.to_lowercase();
+            self.keywords
+                .iter()
+                .any(|keyword| contains_keyword(&context, keyword))
+        })
+    }
+    /// Scores how likely a match is to be a SIN, without considering keywords.
+    fn confidence(text: &str, sin: SIN, start: usize, end: usize, grouped: bool) -> f32 {
+        let mut confidence = 0.5;
+        if grouped {
+            confidence += 0.3;
+        }
+        let before = text[..start].chars().next_back();
+        let after = text[end..].chars().next();
+        if before.is_some_and(char::is_alphabetic) || after.is_some_and(char::is_alphabetic) {
+            confidence -= 0.3;
+        }
+        if matches!(before, Some('#' | '$' | '+' | '/' | '(' | '-'))
+            || matches!(after, Some('%' | '/' | ')'))
+        {
+            confidence -= 0.2;
+        }
+        let digits = sin.digits();
+        let distinct = (0..10).filter(|d| digits.contains(d)).count();
+        if sin.is_placeholder() {
+            confidence -= 0.3;
+        }
+        if !sin.plausibility().is_plausible() || distinct < 4 {
+            confidence -= 0.2;
+        }
+        f32::clamp(confidence, 0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn candidates() {
+        let scanner = Scanner::new().with_threshold(0.0);
+        assert!(scanner.scan("0464542860").is_empty());
+        assert!(scanner.scan("046-454 286").is_empty());
+        assert!(scanner.scan("046 454 287").is_empty());
+        let matches = scanner.scan("é046.454.286é");
+        assert_eq!(matches.len(), 1);
+        assert_eq!((matches[0].start, matches[0].end), (2, 13));
+        assert!((matches[0].confidence - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn suspicious_context() {
+        let scanner = Scanner::new();
+        assert!(scanner.scan("#046454286").is_empty());
+        assert!(scanner.scan("123 456 782").is_empty());
+        assert_eq!(scanner.scan("SIN: 046454286").len(), 1);
+    }
+
+    #[test]
+    fn keywords() {
+        let scanner = Scanner::new().require_keyword();
+        assert_eq!(
+            scanner
+                .scan("Numéro d'assurance sociale : 046 454 286")
+                .len(),
+            1
+        );
+        assert!(scanner.scan("Using 046 454 286").is_empty());
+        let matches = Scanner::new().scan("Social Insurance #046454286");
+        assert_eq!(matches.len(), 1);
+        assert!((matches[0].confidence - 0.6).abs() < 1e-6);
+        let scanner = Scanner::new()
+            .with_keywords(&["Employee ID"])
+            .require_keyword();
+        assert_eq!(scanner.scan("046454286 (employee id)").len(), 1);
+        assert!(scanner.scan("SIN 046454286").is_empty());
+    }
+}
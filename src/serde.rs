@@ -0,0 +1,239 @@
+//! Serde support. SINs are serialized as strings of 9 digits, and deserialized from any string
+//! that [`SIN::parse`] accepts. The submodules can be used with `#[serde(with = "...")]` to
+//! serialize SINs differently, or to be explicit about which strings are accepted.
+//!
+//! [`SINParseError`] is serialized as an object with a `code` and an English `message`, so it
+//! can be returned from web services as a structured validation error.
+
+use crate::{Locale, SINParseError, SIN};
+use ::serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+impl Serialize for SIN {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.digits_string())
+    }
+}
+
+impl Serialize for SINParseError {
+    /// Serializes the error as an object with two fields:
+    /// - `code`: the code from [`SINParseError::code`]. New codes may be added along with new
+    ///   variants, so clients should handle unknown codes.
+    /// - `message`: the English description from [`SINParseError::message`], which is meant for
+    ///   people and may be reworded.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::SINParseError;
+    /// assert_eq!(
+    ///     serde_json::to_string(&SINParseError::InvalidChecksum).unwrap(),
+    ///     r#"{"code":"invalid_checksum","message":"SIN has an invalid checksum"}"#,
+    /// );
+    /// ```
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("SINParseError", 2)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", self.message(Locale::English))?;
+        state.end()
+    }
+}
+
+struct SINVisitor;
+
+impl<'de> de::Visitor<'de> for SINVisitor {
+    type Value = SIN;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a SIN")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<SIN, E> {
+        SIN::parse(v).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for SIN {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(SINVisitor)
+    }
+}
+
+/// Deserializes SINs from any string that [`SIN::parse`] accepts, such as `046-454-286` or
+/// `046 454 286`. This is the same as the default behaviour, and SINs are serialized as 9 digits.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::SIN;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Employee {
+///     #[serde(with = "canada_sin::serde::lenient")]
+///     sin: SIN,
+/// }
+///
+/// let employee: Employee = serde_json::from_str(r#"{"sin":"046 454 286"}"#).unwrap();
+/// assert_eq!(employee.sin.digits_string(), "046454286");
+/// ```
+pub mod lenient {
+    use crate::SIN;
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes a SIN as a string of 9 digits.
+    pub fn serialize<S: Serializer>(sin: &SIN, serializer: S) -> Result<S::Ok, S::Error> {
+        sin.serialize(serializer)
+    }
+
+    /// Deserializes a SIN from a string in any format.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SIN, D::Error> {
+        SIN::deserialize(deserializer)
+    }
+}
+
+/// Only deserializes SINs from strings of exactly 9 digits, with no separators or whitespace.
+/// SINs are serialized as 9 digits.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::SIN;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Employee {
+///     #[serde(with = "canada_sin::serde::strict")]
+///     sin: SIN,
+/// }
+///
+/// let employee: Employee = serde_json::from_str(r#"{"sin":"046454286"}"#).unwrap();
+/// assert_eq!(employee.sin.digits_string(), "046454286");
+/// assert!(serde_json::from_str::<Employee>(r#"{"sin":"046-454-286"}"#).is_err());
+/// ```
+pub mod strict {
+    use crate::SIN;
+    use ::serde::{de, Deserializer, Serialize, Serializer};
+    use std::fmt;
+
+    struct StrictVisitor;
+
+    impl<'de> de::Visitor<'de> for StrictVisitor {
+        type Value = SIN;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a SIN of exactly 9 digits")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<SIN, E> {
+            if !v.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(E::invalid_value(de::Unexpected::Str(v), &self));
+            }
+            SIN::parse(v).map_err(E::custom)
+        }
+    }
+
+    /// Serializes a SIN as a string of 9 digits.
+    pub fn serialize<S: Serializer>(sin: &SIN, serializer: S) -> Result<S::Ok, S::Error> {
+        sin.serialize(serializer)
+    }
+
+    /// Deserializes a SIN from a string of exactly 9 digits.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SIN, D::Error> {
+        deserializer.deserialize_str(StrictVisitor)
+    }
+}
+
+/// Serializes SINs as integers, for systems that store SINs as numbers. Leading zeros are lost
+/// when a SIN is stored as a number, so they are added back when deserializing.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::SIN;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Employee {
+///     #[serde(with = "canada_sin::serde::as_u32")]
+///     sin: SIN,
+/// }
+///
+/// let employee = Employee { sin: SIN::parse("046454286".to_string()).unwrap() };
+/// assert_eq!(serde_json::to_string(&employee).unwrap(), r#"{"sin":46454286}"#);
+/// let employee: Employee = serde_json::from_str(r#"{"sin":46454286}"#).unwrap();
+/// assert_eq!(employee.sin.digits_string(), "046454286");
+/// assert!(serde_json::from_str::<Employee>(r#"{"sin":46454287}"#).is_err());
+/// ```
+pub mod as_u32 {
+    use crate::SIN;
+    use ::serde::{de, Deserialize, Deserializer, Serializer};
+
+    /// Serializes a SIN as an integer.
+    pub fn serialize<S: Serializer>(sin: &SIN, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(sin.to_number())
+    }
+
+    /// Deserializes a SIN from an integer, checking the checksum.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SIN, D::Error> {
+        SIN::from_number(u32::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn string_roundtrip() {
+        let sin = SIN::parse("046454286".to_string()).unwrap();
+        let json = serde_json::to_string(&sin).unwrap();
+        assert_eq!(json, r#""046454286""#);
+        assert_eq!(serde_json::from_str::<SIN>(&json).unwrap(), sin);
+        assert_eq!(
+            serde_json::from_str::<SIN>(r#""046-454-286""#).unwrap(),
+            sin
+        );
+        assert!(serde_json::from_str::<SIN>(r#""046-454-287""#).is_err());
+        assert!(serde_json::from_str::<SIN>("46454286").is_err());
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn strict() {
+        #[derive(Debug, ::serde::Deserialize)]
+        struct Strict(#[serde(with = "super::strict")] SIN);
+        assert_eq!(
+            serde_json::from_str::<Strict>(r#""046454286""#).unwrap().0,
+            SIN::parse("046454286".to_string()).unwrap()
+        );
+        for s in [
+            r#"" 046454286""#,
+            r#""046 454 286""#,
+            r#""04645428""#,
+            r#""046454287""#,
+        ] {
+            assert!(serde_json::from_str::<Strict>(s).is_err());
+        }
+    }
+
+    #[test]
+    fn number_conversion() {
+        assert_eq!(
+            SIN::from_number(999_999_998).unwrap().to_number(),
+            999_999_998
+        );
+        assert_eq!(SIN::from_number(0).unwrap().digits_string(), "000000000");
+        assert_eq!(SIN::from_number(1_000_000_000), Err(SINParseError::TooLong));
+    }
+
+    #[test]
+    fn error_wire_shape() {
+        let err = SIN::parse("0464542860").unwrap_err();
+        assert_eq!(
+            serde_json::to_value(err).unwrap(),
+            serde_json::json!({ "code": "too_long", "message": "SIN is longer than 9 digits" })
+        );
+        assert_eq!(
+            serde_json::to_value(SINParseError::MisplacedSeparator).unwrap()["code"],
+            "misplaced_separator"
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Adapters for `serde_with`'s `#[serde_as]`, as an alternative to the modules in
+//! [`canada_sin::serde`](crate::serde).
+
+use crate::SIN;
+use ::serde::{Deserialize, Deserializer, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+
+/// Serializes SINs with dashes, like `046-454-286`, and deserializes them from any string that
+/// [`SIN::parse`] accepts.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::SIN;
+/// use serde::{Deserialize, Serialize};
+/// use serde_with::serde_as;
+///
+/// #[serde_as]
+/// #[derive(Serialize, Deserialize)]
+/// struct Employee {
+///     #[serde_as(as = "canada_sin::Dashed")]
+///     sin: SIN,
+///     #[serde_as(as = "Option<canada_sin::Dashed>")]
+///     spouse_sin: Option<SIN>,
+/// }
+///
+/// let json = r#"{"sin":"046454286","spouse_sin":null}"#;
+/// let employee: Employee = serde_json::from_str(json).unwrap();
+/// assert_eq!(
+///     serde_json::to_string(&employee).unwrap(),
+///     r#"{"sin":"046-454-286","spouse_sin":null}"#,
+/// );
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Dashed;
+
+impl SerializeAs<SIN> for Dashed {
+    fn serialize_as<S: Serializer>(sin: &SIN, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut buf = [0; 11];
+        serializer.serialize_str(sin.format_into_buffer(&mut buf, true))
+    }
+}
+
+impl<'de> DeserializeAs<'de, SIN> for Dashed {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<SIN, D::Error> {
+        SIN::deserialize(deserializer)
+    }
+}
+
+/// Serializes SINs with all but the last 3 digits hidden, like `***-***-286`, for API responses
+/// and exports that shouldn't contain full SINs. Masked SINs can't be deserialized.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::SIN;
+/// use serde::Serialize;
+/// use serde_with::serde_as;
+///
+/// #[serde_as]
+/// #[derive(Serialize)]
+/// struct Employee {
+///     #[serde_as(as = "canada_sin::Masked")]
+///     sin: SIN,
+/// }
+///
+/// let employee = Employee { sin: SIN::parse("046454286".to_string()).unwrap() };
+/// assert_eq!(serde_json::to_string(&employee).unwrap(), r#"{"sin":"***-***-286"}"#);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Masked;
+
+impl SerializeAs<SIN> for Masked {
+    fn serialize_as<S: Serializer>(sin: &SIN, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&sin.masked())
+    }
+}
+
+/// Serializes SINs as integers, like [`canada_sin::serde::as_u32`](crate::serde::as_u32).
+///
+/// ## Examples
+/// ```
+/// use canada_sin::SIN;
+/// use serde::{Deserialize, Serialize};
+/// use serde_with::serde_as;
+///
+/// #[serde_as]
+/// #[derive(Serialize, Deserialize)]
+/// struct Employee {
+///     #[serde_as(as = "canada_sin::AsInteger")]
+///     sin: SIN,
+/// }
+///
+/// let employee: Employee = serde_json::from_str(r#"{"sin":46454286}"#).unwrap();
+/// assert_eq!(employee.sin.digits_string(), "046454286");
+/// assert_eq!(serde_json::to_string(&employee).unwrap(), r#"{"sin":46454286}"#);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct AsInteger;
+
+impl SerializeAs<SIN> for AsInteger {
+    fn serialize_as<S: Serializer>(sin: &SIN, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serde::as_u32::serialize(sin, serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, SIN> for AsInteger {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<SIN, D::Error> {
+        crate::serde::as_u32::deserialize(deserializer)
+    }
+}
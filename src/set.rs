@@ -0,0 +1,273 @@
+//! A compact set of SINs.
+
+use crate::{SIN, VALID_SINS};
+use std::{convert::TryInto, error::Error, fmt, iter::FromIterator};
+
+/// The number of SINs in each chunk.
+const CHUNK_BITS: u32 = 1 << 16;
+/// The number of words in each chunk.
+const CHUNK_WORDS: usize = CHUNK_BITS as usize / 64;
+/// The number of chunks needed for every valid SIN.
+const CHUNKS: usize = VALID_SINS as usize / CHUNK_BITS as usize + 1;
+/// The size of a chunk when serialized: a 2 byte index, then the words.
+const SERIALIZED_CHUNK_BYTES: usize = 2 + CHUNK_WORDS * 8;
+
+type Chunk = Box<[u64; CHUNK_WORDS]>;
+
+/// A set of SINs, stored as a bitset with one bit per valid SIN. Only the check digit is
+/// redundant, so there are 100 million possible SINs. The bitset is split into chunks of 65536
+/// SINs, and chunks are only allocated when they contain a SIN, so the set uses 8 KiB per chunk
+/// in use, up to about 12 MB when every chunk is in use.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{SINSet, SIN};
+/// let mut set = SINSet::new();
+/// let sin = SIN::parse("046454286".to_string()).unwrap();
+/// assert!(set.insert(sin));
+/// assert!(!set.insert(sin));
+/// assert!(set.contains(&sin));
+/// assert!(!set.contains(&SIN::parse("000000000".to_string()).unwrap()));
+///
+/// let bytes = set.to_bytes();
+/// assert_eq!(SINSet::from_bytes(&bytes).unwrap(), set);
+/// ```
+#[derive(Clone)]
+pub struct SINSet {
+    chunks: Vec<Option<Chunk>>,
+    len: usize,
+}
+
+impl Default for SINSet {
+    fn default() -> Self {
+        Self {
+            chunks: vec![None; CHUNKS],
+            len: 0,
+        }
+    }
+}
+
+impl PartialEq for SINSet {
+    fn eq(&self, other: &Self) -> bool {
+        let is_empty = |chunk: &Chunk| chunk.iter().all(|&word| word == 0);
+        self.len == other.len
+            && self
+                .chunks
+                .iter()
+                .zip(other.chunks.iter())
+                .all(|chunks| match chunks {
+                    (Some(a), Some(b)) => a == b,
+                    (Some(chunk), None) | (None, Some(chunk)) => is_empty(chunk),
+                    (None, None) => true,
+                })
+    }
+}
+
+impl Eq for SINSet {}
+
+impl fmt::Debug for SINSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Where the bit for a SIN is: the chunk, the word in the chunk, and the bit in the word.
+fn locate(sin: &SIN) -> (usize, usize, u64) {
+    let number = sin.body_number();
+    let chunk = (number / CHUNK_BITS) as usize;
+    let bit = (number % CHUNK_BITS) as usize;
+    (chunk, bit / 64, 1 << (bit % 64))
+}
+
+impl SINSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Adds a SIN to the set, returning whether it was newly added.
+    pub fn insert(&mut self, sin: SIN) -> bool {
+        let (chunk, word, mask) = locate(&sin);
+        let chunk = self.chunks[chunk].get_or_insert_with(|| Box::new([0; CHUNK_WORDS]));
+        let added = chunk[word] & mask == 0;
+        chunk[word] |= mask;
+        if added {
+            self.len += 1;
+        }
+        added
+    }
+    /// Removes a SIN from the set, returning whether it was in the set.
+    pub fn remove(&mut self, sin: &SIN) -> bool {
+        let (chunk, word, mask) = locate(sin);
+        let removed = match &mut self.chunks[chunk] {
+            Some(chunk) => {
+                let removed = chunk[word] & mask != 0;
+                chunk[word] &= !mask;
+                removed
+            }
+            None => false,
+        };
+        if removed {
+            self.len -= 1;
+        }
+        removed
+    }
+    /// Is the SIN in the set?
+    pub fn contains(&self, sin: &SIN) -> bool {
+        let (chunk, word, mask) = locate(sin);
+        match &self.chunks[chunk] {
+            Some(chunk) => chunk[word] & mask != 0,
+            None => false,
+        }
+    }
+    /// The number of SINs in the set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Is the set empty?
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Iterates over the SINs in the set, in order.
+    pub fn iter(&self) -> impl Iterator<Item = SIN> + '_ {
+        self.chunks
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, chunk)| chunk.as_ref().map(|chunk| (idx, chunk)))
+            .flat_map(|(chunk_idx, chunk)| {
+                chunk.iter().enumerate().flat_map(move |(word_idx, &word)| {
+                    (0..64)
+                        .filter(move |bit| word & (1 << bit) != 0)
+                        .map(move |bit| {
+                            let number = chunk_idx as u32 * CHUNK_BITS + word_idx as u32 * 64 + bit;
+                            SIN::from_body_number(number)
+                        })
+                })
+            })
+    }
+    /// Serializes the set into bytes that can be read with [`SINSet::from_bytes`]. Each chunk in
+    /// use is stored as its index as a little-endian `u16`, followed by its bits as 1024
+    /// little-endian `u64`s.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for (idx, chunk) in self.chunks.iter().enumerate() {
+            if let Some(chunk) = chunk {
+                if chunk.iter().all(|&word| word == 0) {
+                    continue;
+                }
+                bytes.extend_from_slice(&(idx as u16).to_le_bytes());
+                for word in chunk.iter() {
+                    bytes.extend_from_slice(&word.to_le_bytes());
+                }
+            }
+        }
+        bytes
+    }
+    /// Deserializes a set created with [`SINSet::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SINSetDecodeError> {
+        if bytes.len() % SERIALIZED_CHUNK_BYTES != 0 {
+            return Err(SINSetDecodeError);
+        }
+        let mut set = Self::new();
+        let mut last_idx = None;
+        for serialized in bytes.chunks(SERIALIZED_CHUNK_BYTES) {
+            let idx = usize::from(u16::from_le_bytes([serialized[0], serialized[1]]));
+            if idx >= CHUNKS || last_idx.is_some_and(|last_idx| idx <= last_idx) {
+                return Err(SINSetDecodeError);
+            }
+            last_idx = Some(idx);
+            let mut chunk = Box::new([0; CHUNK_WORDS]);
+            for (word, word_bytes) in chunk.iter_mut().zip(serialized[2..].chunks(8)) {
+                *word = u64::from_le_bytes(word_bytes.try_into().unwrap());
+                set.len += word.count_ones() as usize;
+            }
+            set.chunks[idx] = Some(chunk);
+        }
+        // bits past the last valid SIN must not be set
+        let last = SIN::from_body_number(VALID_SINS - 1);
+        let (chunk, word, mask) = locate(&last);
+        if let Some(chunk) = &set.chunks[chunk] {
+            if chunk[word] & !(mask | (mask - 1)) != 0 || chunk[word + 1..].iter().any(|&w| w != 0)
+            {
+                return Err(SINSetDecodeError);
+            }
+        }
+        Ok(set)
+    }
+}
+
+impl Extend<SIN> for SINSet {
+    fn extend<T: IntoIterator<Item = SIN>>(&mut self, iter: T) {
+        for sin in iter {
+            self.insert(sin);
+        }
+    }
+}
+
+impl FromIterator<SIN> for SINSet {
+    fn from_iter<T: IntoIterator<Item = SIN>>(iter: T) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+/// An error from deserializing a [`SINSet`] from invalid bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SINSetDecodeError;
+
+impl fmt::Display for SINSetDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid serialized SIN set")
+    }
+}
+
+impl Error for SINSetDecodeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SINRange;
+
+    #[allow(clippy::unnecessary_to_owned)]
+    fn sin(s: &str) -> SIN {
+        SIN::parse(s.to_string()).unwrap()
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut set = SINSet::new();
+        assert!(set.is_empty());
+        set.insert(sin("999999998"));
+        set.insert(sin("000000000"));
+        assert_eq!(set.len(), 2);
+        assert!(set.remove(&sin("999999998")));
+        assert!(!set.remove(&sin("999999998")));
+        assert!(!set.remove(&sin("046454286")));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![sin("000000000")]);
+    }
+
+    #[test]
+    fn iter_in_order() {
+        let range = SINRange::new(sin("000654327"), sin("000754325"));
+        let set: SINSet = range.iter().step_by(7).collect();
+        assert_eq!(set.len(), range.iter().step_by(7).count());
+        assert!(set.iter().eq(range.iter().step_by(7)));
+    }
+
+    #[test]
+    fn serialization() {
+        let mut set: SINSet = [sin("000000000"), sin("999999998"), sin("734323843")]
+            .iter()
+            .copied()
+            .collect();
+        set.remove(&sin("734323843"));
+        let bytes = set.to_bytes();
+        assert_eq!(bytes.len(), 2 * SERIALIZED_CHUNK_BYTES);
+        assert_eq!(SINSet::from_bytes(&bytes), Ok(set));
+        assert_eq!(SINSet::from_bytes(&bytes[1..]), Err(SINSetDecodeError));
+        let mut bad = bytes.clone();
+        *bad.last_mut().unwrap() = 0xff;
+        assert_eq!(SINSet::from_bytes(&bad), Err(SINSetDecodeError));
+        assert_eq!(SINSet::from_bytes(&[]), Ok(SINSet::new()));
+    }
+}
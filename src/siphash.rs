@@ -0,0 +1,71 @@
+//! SipHash-2-4, a keyed hash function. This is implemented here instead of using the standard
+//! library's hasher, since that hasher's algorithm isn't guaranteed to stay the same.
+
+/// One round of SipHash.
+fn round(v: &mut [u64; 4]) {
+    v[0] = v[0].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(13) ^ v[0];
+    v[0] = v[0].rotate_left(32);
+    v[2] = v[2].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(16) ^ v[2];
+    v[0] = v[0].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(21) ^ v[0];
+    v[2] = v[2].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(17) ^ v[2];
+    v[2] = v[2].rotate_left(32);
+}
+
+/// Hashes `data` with a 128-bit key.
+pub(crate) fn hash(key: &[u8; 16], data: &[u8]) -> u64 {
+    let mut k = [0; 8];
+    k.copy_from_slice(&key[..8]);
+    let k0 = u64::from_le_bytes(k);
+    k.copy_from_slice(&key[8..]);
+    let k1 = u64::from_le_bytes(k);
+    let mut v = [
+        k0 ^ 0x736f_6d65_7073_6575,
+        k1 ^ 0x646f_7261_6e64_6f6d,
+        k0 ^ 0x6c79_6765_6e65_7261,
+        k1 ^ 0x7465_6462_7974_6573,
+    ];
+    let mut blocks = data.chunks_exact(8);
+    for block in &mut blocks {
+        let mut m = [0; 8];
+        m.copy_from_slice(block);
+        let m = u64::from_le_bytes(m);
+        v[3] ^= m;
+        round(&mut v);
+        round(&mut v);
+        v[0] ^= m;
+    }
+    let mut last = [0; 8];
+    last[..blocks.remainder().len()].copy_from_slice(blocks.remainder());
+    let m = u64::from_le_bytes(last) | ((data.len() as u64) << 56);
+    v[3] ^= m;
+    round(&mut v);
+    round(&mut v);
+    v[0] ^= m;
+    v[2] ^= 0xff;
+    for _ in 0..4 {
+        round(&mut v);
+    }
+    v[0] ^ v[1] ^ v[2] ^ v[3]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference_vectors() {
+        let mut key = [0; 16];
+        for (idx, byte) in key.iter_mut().enumerate() {
+            *byte = idx as u8;
+        }
+        assert_eq!(hash(&key, &[]), 0x726f_db47_dd0e_0e31);
+        let data: Vec<u8> = (0..15).collect();
+        assert_eq!(hash(&key, &data), 0xa129_ca61_49be_45e5);
+        let data: Vec<u8> = (0..8).collect();
+        assert_eq!(hash(&key, &data), 0x93f5_f579_9a93_2462);
+    }
+}
@@ -0,0 +1,47 @@
+//! Reading SINs aloud, for screen readers and call centre scripts.
+
+use crate::{Locale, SIN};
+
+const ENGLISH: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const FRENCH: [&str; 10] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+
+impl SIN {
+    /// Spells out each digit of the SIN in words, with the groups of 3 separated by commas so
+    /// that screen readers and text-to-speech systems pause between them.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{Locale, SIN};
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// assert_eq!(
+    ///     sin.to_spoken_string(Locale::English),
+    ///     "zero four six, four five four, two eight six",
+    /// );
+    /// assert_eq!(
+    ///     sin.to_spoken_string(Locale::French),
+    ///     "zéro quatre six, quatre cinq quatre, deux huit six",
+    /// );
+    /// ```
+    pub fn to_spoken_string(&self, locale: Locale) -> String {
+        let words = match locale {
+            Locale::English => &ENGLISH,
+            Locale::French => &FRENCH,
+        };
+        let groups: Vec<String> = self
+            .digits()
+            .chunks(3)
+            .map(|group| {
+                let group: Vec<&str> = group
+                    .iter()
+                    .map(|&digit| words[usize::from(digit)])
+                    .collect();
+                group.join(" ")
+            })
+            .collect();
+        groups.join(", ")
+    }
+}
@@ -0,0 +1,71 @@
+//! Recording SINs in `tracing` spans and events without exposing them. `tracing`'s `Value` trait
+//! can't be implemented outside of `tracing`, so SINs are recorded through [`MaskedSIN`], which
+//! only shows the last 3 digits.
+
+use crate::SIN;
+use ::tracing::field::{display, DisplayValue};
+use std::fmt;
+
+/// A SIN that only shows its last 3 digits when formatted, like `***-***-286`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct MaskedSIN(SIN);
+
+impl fmt::Display for MaskedSIN {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.masked())
+    }
+}
+
+impl fmt::Debug for MaskedSIN {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl MaskedSIN {
+    /// Wraps a SIN so that it only shows its last 3 digits when formatted.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{tracing::MaskedSIN, SIN};
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// assert_eq!(MaskedSIN::new(sin).to_string(), "***-***-286");
+    /// assert_eq!(format!("{:?}", MaskedSIN::new(sin)), "***-***-286");
+    /// ```
+    pub fn new(sin: SIN) -> Self {
+        Self(sin)
+    }
+}
+
+/// Returns a `tracing` field value that records the masked form of `sin`. This is what
+/// [`sin_field!`](crate::sin_field) expands to.
+pub fn field(sin: &SIN) -> DisplayValue<MaskedSIN> {
+    display(MaskedSIN::new(*sin))
+}
+
+/// Records a SIN in a `tracing` span or event, showing only the last 3 digits.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{sin_field, SIN};
+/// let sin = SIN::parse("046454286".to_string()).unwrap();
+/// tracing::info!(sin = sin_field!(sin), "looked up employee");
+/// ```
+#[macro_export]
+macro_rules! sin_field {
+    ($sin:expr) => {
+        $crate::tracing::field(&$sin)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn field_is_masked() {
+        let sin = SIN::parse("046454286".to_string()).unwrap();
+        assert_eq!(format!("{:?}", sin_field!(sin)), "***-***-286");
+    }
+}
@@ -0,0 +1,126 @@
+//! SINs whose category is part of their type.
+
+use crate::{SINParseError, SIN};
+use core::{fmt, marker::PhantomData, ops::Deref};
+
+/// A category of SINs, used as the type parameter of [`TypedSIN`].
+pub trait SINCategory {
+    /// Could `sin` be in this category?
+    fn contains(sin: &SIN) -> bool;
+}
+
+/// SINs given to temporary residents, which start with 9.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum Temporary {}
+
+impl SINCategory for Temporary {
+    fn contains(sin: &SIN) -> bool {
+        sin.could_be(crate::SINType::TemporaryResident)
+    }
+}
+
+/// Numbers that could be business numbers, which start with 7 or 8.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum Business {}
+
+impl SINCategory for Business {
+    fn contains(sin: &SIN) -> bool {
+        sin.could_be(crate::SINType::BusinessNumber)
+    }
+}
+
+/// SINs that could be given to citizens and permanent residents, which start with 1 to 7. SINs
+/// starting with 7 could also be business numbers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum Permanent {}
+
+impl SINCategory for Permanent {
+    fn contains(sin: &SIN) -> bool {
+        (1..=7).contains(&sin.first_digit())
+    }
+}
+
+/// A SIN that is known to be in the category `C`, so functions can require a category in their
+/// signature, like `fn hire(sin: TypedSIN<Permanent>)`, instead of checking at runtime.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{SINParseError, Temporary, TypedSIN, SIN};
+/// let sin: TypedSIN<Temporary> = TypedSIN::parse("946454287".to_string()).unwrap();
+/// assert_eq!(sin.digits_string(), "946454287");
+/// assert_eq!(
+///     TypedSIN::<Temporary>::parse("046454286".to_string()),
+///     Err(SINParseError::WrongType),
+/// );
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Ord, PartialOrd)]
+pub struct TypedSIN<C> {
+    sin: SIN,
+    category: PhantomData<C>,
+}
+
+impl<C: SINCategory> TypedSIN<C> {
+    /// Checks that `sin` is in the category, returning `None` if it isn't.
+    pub fn new(sin: SIN) -> Option<Self> {
+        if C::contains(&sin) {
+            Some(Self {
+                sin,
+                category: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+    /// Parses a SIN like [`SIN::parse`], but fails with [`SINParseError::WrongType`] if the SIN
+    /// isn't in the category. This is like [`SIN::parse_expecting`], with the category in the
+    /// type.
+    pub fn parse(s: impl AsRef<str>) -> Result<Self, SINParseError> {
+        Self::new(SIN::parse(s)?).ok_or(SINParseError::WrongType)
+    }
+}
+
+impl<C> TypedSIN<C> {
+    /// Returns the SIN, without its category.
+    pub fn into_inner(self) -> SIN {
+        self.sin
+    }
+}
+
+impl<C> Deref for TypedSIN<C> {
+    type Target = SIN;
+
+    fn deref(&self) -> &SIN {
+        &self.sin
+    }
+}
+
+impl<C> fmt::Debug for TypedSIN<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.sin, f)
+    }
+}
+
+impl<C> fmt::Display for TypedSIN<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.sin, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn categories() {
+        let sin = SIN::parse("734323843".to_string()).unwrap();
+        assert!(TypedSIN::<Business>::new(sin).is_some());
+        assert!(TypedSIN::<Permanent>::new(sin).is_some());
+        assert!(TypedSIN::<Temporary>::new(sin).is_none());
+        let sin = SIN::parse("046454286".to_string()).unwrap();
+        assert!(TypedSIN::<Permanent>::new(sin).is_none());
+        let typed = TypedSIN::<Permanent>::parse("346454382".to_string()).unwrap();
+        assert_eq!(typed.to_string(), "346-454-382");
+        assert_eq!(format!("{:?}", typed), format!("{:?}", typed.into_inner()));
+    }
+}
@@ -0,0 +1,130 @@
+//! A compact set of SIN types.
+
+use crate::{SINType, ALL_TYPES, SIN};
+use core::{fmt, iter::FromIterator};
+
+/// A set of [`SINType`]s, stored as bits. This is `Copy` and much cheaper to store and compare
+/// than a `Vec<SINType>`.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{SIN, SINType, SINTypes};
+/// let types = SIN::parse("734323843".to_string()).unwrap().type_set();
+/// assert!(types.contains(SINType::Yukon));
+/// let western: SINTypes = [SINType::BritishColumbia, SINType::Alberta].iter().copied().collect();
+/// assert_eq!(
+///     types.intersection(western).iter().collect::<Vec<_>>(),
+///     vec![SINType::BritishColumbia],
+/// );
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct SINTypes {
+    bits: u32,
+}
+
+impl SINTypes {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The bit used for `ty`.
+    fn bit(ty: SINType) -> u32 {
+        1 << ty as u32
+    }
+    /// Is `ty` in the set?
+    pub fn contains(self, ty: SINType) -> bool {
+        self.bits & Self::bit(ty) != 0
+    }
+    /// Adds `ty` to the set.
+    pub fn insert(&mut self, ty: SINType) {
+        self.bits |= Self::bit(ty);
+    }
+    /// Removes `ty` from the set.
+    pub fn remove(&mut self, ty: SINType) {
+        self.bits &= !Self::bit(ty);
+    }
+    /// The types that are in both sets.
+    pub fn intersection(self, other: SINTypes) -> SINTypes {
+        Self {
+            bits: self.bits & other.bits,
+        }
+    }
+    /// The types that are in either set.
+    pub fn union(self, other: SINTypes) -> SINTypes {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+    /// The number of types in the set.
+    pub fn len(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+    /// Is the set empty?
+    pub fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+    /// Iterates over the types in the set, in declaration order.
+    pub fn iter(self) -> impl Iterator<Item = SINType> {
+        ALL_TYPES
+            .iter()
+            .copied()
+            .filter(move |&ty| self.contains(ty))
+    }
+}
+
+impl FromIterator<SINType> for SINTypes {
+    fn from_iter<I: IntoIterator<Item = SINType>>(iter: I) -> Self {
+        let mut types = Self::new();
+        for ty in iter {
+            types.insert(ty);
+        }
+        types
+    }
+}
+
+impl fmt::Debug for SINTypes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl SIN {
+    /// All types the SIN *could* be, as a [`SINTypes`]. This contains the same types as
+    /// [`SIN::types`], but doesn't allocate and is cheaper to store and compare.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{SIN, SINType};
+    /// let types = SIN::parse("346454382".to_string()).unwrap().type_set();
+    /// assert_eq!(types.len(), 1);
+    /// assert!(types.contains(SINType::Quebec));
+    /// ```
+    pub fn type_set(&self) -> SINTypes {
+        Self::types_for_first_digit(self.first_digit())
+            .iter()
+            .copied()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_operations() {
+        let mut types = SINTypes::new();
+        assert!(types.is_empty());
+        for ty in SINType::all() {
+            types.insert(ty);
+        }
+        assert_eq!(types.len(), ALL_TYPES.len());
+        assert!(types.iter().eq(SINType::all()));
+        types.remove(SINType::Ontario);
+        assert!(!types.contains(SINType::Ontario));
+        let ontario: SINTypes = std::iter::once(SINType::Ontario).collect();
+        assert!(types.intersection(ontario).is_empty());
+        assert_eq!(types.union(ontario).len(), ALL_TYPES.len());
+        assert_eq!(format!("{:?}", ontario), "{Ontario}");
+    }
+}
@@ -0,0 +1,117 @@
+//! IRCC Unique Client Identifiers.
+
+use crate::SIN;
+use std::{error::Error, fmt, str::FromStr};
+
+/// An error resulting from parsing a UCI.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UCIParseError {
+    /// The UCI doesn't have 8 or 10 digits.
+    InvalidLength,
+    /// The UCI has a character that isn't a digit, space or hyphen.
+    InvalidCharacter,
+}
+
+impl fmt::Display for UCIParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidLength => "UCI doesn't have 8 or 10 digits",
+            Self::InvalidCharacter => "UCI has an invalid character",
+        })
+    }
+}
+
+impl Error for UCIParseError {}
+
+/// A Unique Client Identifier (UCI), which Immigration, Refugees and Citizenship Canada uses to
+/// identify clients. It is also called a client ID. Older UCIs have 8 digits, written like
+/// `1234-5678`, and newer UCIs have 10 digits, written like `12-3456-7890`.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::UCI;
+/// let uci = UCI::parse("1234 5678".to_string()).unwrap();
+/// assert_eq!(uci.to_string(), "1234-5678");
+/// let uci = UCI::parse("1234567890".to_string()).unwrap();
+/// assert_eq!(uci.to_string(), "12-3456-7890");
+/// assert_eq!(uci.digits_string(), "1234567890");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct UCI {
+    digits: Vec<u8>,
+}
+
+impl UCI {
+    /// Parses a UCI. Spaces and hyphens are ignored.
+    pub fn parse(s: String) -> Result<Self, UCIParseError> {
+        if !s
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == ' ' || c == '-')
+        {
+            return Err(UCIParseError::InvalidCharacter);
+        }
+        let digits = SIN::extract_digits(&s);
+        if digits.len() != 8 && digits.len() != 10 {
+            return Err(UCIParseError::InvalidLength);
+        }
+        Ok(Self { digits })
+    }
+    /// Returns the 8 or 10 digits of the UCI.
+    pub fn digits(&self) -> &[u8] {
+        &self.digits
+    }
+    /// Returns the UCI as digits, without hyphens.
+    pub fn digits_string(&self) -> String {
+        self.digits.iter().map(|d| d.to_string()).collect()
+    }
+}
+
+impl FromStr for UCI {
+    type Err = UCIParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.to_string())
+    }
+}
+
+impl fmt::Display for UCI {
+    /// Formats the UCI with hyphens, like `1234-5678` or `12-3456-7890`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hyphens: &[usize] = if self.digits.len() == 8 {
+            &[4]
+        } else {
+            &[2, 6]
+        };
+        for (idx, digit) in self.digits.iter().enumerate() {
+            if hyphens.contains(&idx) {
+                f.write_str("-")?;
+            }
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_uci() {
+        let uci: UCI = "12-3456-7890".parse().unwrap();
+        assert_eq!(uci.digits().len(), 10);
+        assert_eq!(
+            UCI::parse("123456789".to_string()),
+            Err(UCIParseError::InvalidLength)
+        );
+        assert_eq!(
+            UCI::parse("".to_string()),
+            Err(UCIParseError::InvalidLength)
+        );
+        assert_eq!(
+            UCI::parse("1234/5678".to_string()),
+            Err(UCIParseError::InvalidCharacter)
+        );
+    }
+}
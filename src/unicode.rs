@@ -0,0 +1,46 @@
+//! Support for digits from scripts other than ASCII.
+
+/// The zero of every run of decimal digits in Unicode 14.0.0. Every decimal digit is
+/// part of a run of 10 consecutive code points from zero to nine.
+const DIGIT_ZEROS: [u32; 66] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0,
+    0x11650, 0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x16A60, 0x16AC0,
+    0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E950, 0x1FBF0,
+];
+
+/// The value of a Unicode decimal digit (general category `Nd`), in any script.
+pub(crate) fn digit_value(khar: char) -> Option<u8> {
+    let code = u32::from(khar);
+    let idx = match DIGIT_ZEROS.binary_search(&code) {
+        Ok(idx) => idx,
+        Err(0) => return None,
+        Err(idx) => idx - 1,
+    };
+    let value = code - DIGIT_ZEROS[idx];
+    if value < 10 {
+        Some(value as u8)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digit_values() {
+        assert_eq!(digit_value('7'), Some(7));
+        assert_eq!(digit_value('０'), Some(0));
+        assert_eq!(digit_value('９'), Some(9));
+        assert_eq!(digit_value('٣'), Some(3));
+        assert_eq!(digit_value('𝟠'), Some(8));
+        assert_eq!(digit_value('a'), None);
+        assert_eq!(digit_value('²'), None);
+        assert_eq!(digit_value('½'), None);
+        assert_eq!(digit_value('\0'), None);
+    }
+}
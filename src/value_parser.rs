@@ -0,0 +1,93 @@
+//! Support for using [`SIN`] as a `clap` argument.
+
+use crate::SIN;
+use clap::{
+    builder::{TypedValueParser, ValueParserFactory},
+    error::ErrorKind,
+    Arg, Command, Error,
+};
+use std::ffi::OsStr;
+
+/// A `clap` value parser for SINs, used automatically by `value_parser!(SIN)` and
+/// `#[arg(value_parser)]`. Invalid SINs are rejected when the arguments are parsed, with an error
+/// message saying what was wrong with the SIN.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::SIN;
+/// use clap::{value_parser, Arg, Command};
+/// let cmd = Command::new("check").arg(Arg::new("sin").value_parser(value_parser!(SIN)));
+/// let matches = cmd.clone().try_get_matches_from(["check", "046-454-286"]).unwrap();
+/// assert_eq!(
+///     matches.get_one::<SIN>("sin").unwrap().digits_string(),
+///     "046454286",
+/// );
+/// assert!(cmd.try_get_matches_from(["check", "046-454-287"]).is_err());
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SINValueParser;
+
+impl TypedValueParser for SINValueParser {
+    type Value = SIN;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let value = value
+            .to_str()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+        SIN::parse(value).map_err(|err| {
+            let arg = arg
+                .map(|arg| arg.to_string())
+                .unwrap_or_else(|| "...".to_string());
+            Error::raw(
+                ErrorKind::ValueValidation,
+                format!("invalid value '{}' for '{}': {}\n", value, arg, err),
+            )
+            .with_cmd(cmd)
+        })
+    }
+}
+
+impl ValueParserFactory for SIN {
+    type Parser = SINValueParser;
+
+    fn value_parser() -> Self::Parser {
+        SINValueParser
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::value_parser;
+
+    fn command() -> Command {
+        Command::new("test").arg(Arg::new("sin").long("sin").value_parser(value_parser!(SIN)))
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn parses_valid_sin() {
+        let matches = command()
+            .try_get_matches_from(["test", "--sin", "046 454 286"])
+            .unwrap();
+        assert_eq!(
+            matches.get_one::<SIN>("sin"),
+            Some(&SIN::parse("046454286".to_string()).unwrap())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_sin() {
+        let err = command()
+            .try_get_matches_from(["test", "--sin", "12345678"])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+        assert!(err.to_string().contains("SIN is less than 9 digits"));
+        assert!(err.to_string().contains("--sin <sin>"));
+    }
+}
@@ -0,0 +1,81 @@
+//! A wrapper for values that are known to have passed validation.
+
+use crate::{PersonInfo, PolicyViolation, SINVerifier, ValidationPolicy, VerificationResult, SIN};
+use std::ops::Deref;
+
+/// A value that has passed a [`ValidationPolicy`] or been confirmed by a [`SINVerifier`]. A
+/// `Verified` can only be created by this crate, so functions that take one, like
+/// `fn enroll(sin: Verified<SIN>)`, can rely on the checks having been done.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{ValidationPolicy, Verified, SIN};
+/// fn enroll(sin: Verified<SIN>) -> String {
+///     sin.digits_string()
+/// }
+///
+/// let policy = ValidationPolicy::new().reject_placeholders();
+/// let sin = SIN::parse("046454286".to_string()).unwrap();
+/// assert_eq!(enroll(sin.verify_with(&policy).unwrap()), "046454286");
+/// let placeholder = SIN::parse("000000000".to_string()).unwrap();
+/// assert!(placeholder.verify_with(&policy).is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd)]
+pub struct Verified<T>(T);
+
+impl<T> Verified<T> {
+    /// Returns the value, without the guarantee.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Verified<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Verified<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl Verified<SIN> {
+    /// Verifies that `sin` belongs to `person` using `verifier`. If the SIN wasn't verified, the
+    /// verifier's result is returned as the error.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{Date, MockVerifier, PersonInfo, VerificationResult, Verified, SIN};
+    /// let sin = SIN::parse("046454286".to_string()).unwrap();
+    /// let person = PersonInfo::new("Tremblay", "Jean", Date::new(1985, 2, 28).unwrap());
+    /// let verified = pollster::block_on(Verified::verify(&MockVerifier::new(), sin, &person));
+    /// assert_eq!(verified.unwrap().into_inner(), sin);
+    /// let verifier = MockVerifier::new().with_default(VerificationResult::Mismatch);
+    /// let verified = pollster::block_on(Verified::verify(&verifier, sin, &person));
+    /// assert_eq!(verified, Err(VerificationResult::Mismatch));
+    /// ```
+    pub async fn verify<V: SINVerifier>(
+        verifier: &V,
+        sin: SIN,
+        person: &PersonInfo,
+    ) -> Result<Self, VerificationResult> {
+        match verifier.verify(&sin, person).await {
+            VerificationResult::Verified => Ok(Self(sin)),
+            result => Err(result),
+        }
+    }
+}
+
+impl SIN {
+    /// Checks the SIN against a policy like [`SIN::validate_with`], returning a
+    /// [`Verified<SIN>`](Verified) if it passed.
+    pub fn verify_with(self, policy: &ValidationPolicy) -> Result<Verified<SIN>, PolicyViolation> {
+        self.validate_with(policy)?;
+        Ok(Verified(self))
+    }
+}
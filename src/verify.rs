@@ -0,0 +1,202 @@
+//! A common interface for services that check SINs against the person they belong to.
+
+use crate::{Date, SIN};
+use std::{
+    future::{self, Future},
+    pin::Pin,
+};
+
+/// Information about the person a SIN is being verified for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PersonInfo {
+    /// The person's surname.
+    pub surname: String,
+    /// The person's given name.
+    pub given_name: String,
+    /// The person's date of birth.
+    pub date_of_birth: Date,
+}
+
+impl PersonInfo {
+    /// Creates the information for a person.
+    pub fn new(surname: &str, given_name: &str, date_of_birth: Date) -> Self {
+        Self {
+            surname: surname.to_string(),
+            given_name: given_name.to_string(),
+            date_of_birth,
+        }
+    }
+}
+
+/// The result of verifying a SIN with a [`SINVerifier`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VerificationResult {
+    /// The SIN belongs to the person.
+    Verified,
+    /// The SIN was issued, but not to this person.
+    Mismatch,
+    /// The SIN was never issued.
+    NotIssued,
+    /// The SIN belongs to the person, but they are recorded as deceased.
+    Deceased,
+    /// The service couldn't verify the SIN, such as because it was unavailable. The string
+    /// describes the error.
+    ServiceError(String),
+}
+
+impl VerificationResult {
+    /// Was the SIN verified as belonging to the person?
+    pub fn is_verified(&self) -> bool {
+        *self == Self::Verified
+    }
+}
+
+/// A service that verifies SINs, such as a government or third-party verification API. This
+/// crate only provides the interface; implementations make the actual requests.
+///
+/// The future is boxed, so implementations usually wrap an `async` block in `Box::pin`:
+/// ```
+/// use canada_sin::{PersonInfo, SINVerifier, VerificationResult, SIN};
+/// use std::{future::Future, pin::Pin};
+///
+/// struct AllowList(Vec<SIN>);
+///
+/// impl SINVerifier for AllowList {
+///     fn verify<'a>(
+///         &'a self,
+///         sin: &'a SIN,
+///         _person: &'a PersonInfo,
+///     ) -> Pin<Box<dyn Future<Output = VerificationResult> + Send + 'a>> {
+///         Box::pin(async move {
+///             if self.0.contains(sin) {
+///                 VerificationResult::Verified
+///             } else {
+///                 VerificationResult::NotIssued
+///             }
+///         })
+///     }
+/// }
+/// ```
+pub trait SINVerifier {
+    /// Checks if `sin` belongs to `person`.
+    fn verify<'a>(
+        &'a self,
+        sin: &'a SIN,
+        person: &'a PersonInfo,
+    ) -> Pin<Box<dyn Future<Output = VerificationResult> + Send + 'a>>;
+}
+
+/// A [`SINVerifier`] for tests, which returns configured results instead of calling a service.
+/// SINs without a configured result get the default result, which is
+/// [`VerificationResult::Verified`] unless it's changed.
+///
+/// ## Examples
+/// ```
+/// use canada_sin::{Date, MockVerifier, PersonInfo, SINVerifier, VerificationResult, SIN};
+/// let deceased = SIN::parse("046454286".to_string()).unwrap();
+/// let verifier = MockVerifier::new()
+///     .with_result(deceased, VerificationResult::Deceased)
+///     .with_default(VerificationResult::Mismatch);
+/// let person = PersonInfo::new("Tremblay", "Jean", Date::new(1985, 2, 28).unwrap());
+/// let result = pollster::block_on(verifier.verify(&deceased, &person));
+/// assert_eq!(result, VerificationResult::Deceased);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockVerifier {
+    results: Vec<(SIN, VerificationResult)>,
+    people: Vec<(SIN, PersonInfo)>,
+    default: VerificationResult,
+}
+
+impl Default for MockVerifier {
+    fn default() -> Self {
+        Self {
+            results: Vec::new(),
+            people: Vec::new(),
+            default: VerificationResult::Verified,
+        }
+    }
+}
+
+impl MockVerifier {
+    /// Creates a verifier that verifies every SIN.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Returns `result` when verifying `sin`, regardless of the person.
+    pub fn with_result(mut self, sin: SIN, result: VerificationResult) -> Self {
+        self.results.retain(|(existing, _)| *existing != sin);
+        self.people.retain(|(existing, _)| *existing != sin);
+        self.results.push((sin, result));
+        self
+    }
+    /// Returns [`VerificationResult::Verified`] when verifying `sin` for `person`, and
+    /// [`VerificationResult::Mismatch`] when verifying it for anyone else.
+    pub fn with_person(self, sin: SIN, person: &PersonInfo) -> Self {
+        let mut verifier = self.with_result(sin, VerificationResult::Verified);
+        verifier.people.retain(|(existing, _)| *existing != sin);
+        verifier.people.push((sin, person.clone()));
+        verifier
+    }
+    /// Returns `result` for SINs without a configured result.
+    pub fn with_default(mut self, result: VerificationResult) -> Self {
+        self.default = result;
+        self
+    }
+}
+
+impl MockVerifier {
+    /// The configured result for verifying `sin` for `person`.
+    fn result(&self, sin: &SIN, person: &PersonInfo) -> VerificationResult {
+        if let Some((_, expected)) = self.people.iter().find(|(known, _)| known == sin) {
+            if expected != person {
+                return VerificationResult::Mismatch;
+            }
+        }
+        self.results
+            .iter()
+            .find(|(known, _)| known == sin)
+            .map_or_else(|| self.default.clone(), |(_, result)| result.clone())
+    }
+}
+
+impl SINVerifier for MockVerifier {
+    fn verify<'a>(
+        &'a self,
+        sin: &'a SIN,
+        person: &'a PersonInfo,
+    ) -> Pin<Box<dyn Future<Output = VerificationResult> + Send + 'a>> {
+        Box::pin(future::ready(self.result(sin, person)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn mock_results() {
+        let sin = SIN::parse("046454286".to_string()).unwrap();
+        let other = SIN::parse("946454287".to_string()).unwrap();
+        let person = PersonInfo::new("Tremblay", "Jean", Date::new(1985, 2, 28).unwrap());
+        let someone_else = PersonInfo::new("Roy", "Marie", Date::new(1990, 1, 1).unwrap());
+        let verifier = MockVerifier::new()
+            .with_person(sin, &person)
+            .with_result(
+                other,
+                VerificationResult::ServiceError("timed out".to_string()),
+            )
+            .with_default(VerificationResult::NotIssued);
+        let verify = |sin, person| pollster::block_on(verifier.verify(sin, person));
+        assert!(verify(&sin, &person).is_verified());
+        assert_eq!(verify(&sin, &someone_else), VerificationResult::Mismatch);
+        assert_eq!(
+            verify(&other, &person),
+            VerificationResult::ServiceError("timed out".to_string())
+        );
+        let unknown = SIN::parse("130692544".to_string()).unwrap();
+        assert_eq!(verify(&unknown, &person), VerificationResult::NotIssued);
+    }
+}
@@ -0,0 +1,66 @@
+//! Parsing SINs without leaving copies of the input in memory, with `zeroize`.
+
+use crate::{luhn, SINParseError, SIN};
+use ::zeroize::{Zeroize, Zeroizing};
+
+impl SIN {
+    /// Parses a SIN like [`SIN::parse`], then wipes the input, so the SIN the user typed doesn't
+    /// stay in freed heap memory. The whole buffer is overwritten with zeros, including any spare
+    /// capacity, and the input is wiped whether or not parsing succeeds. The digits are copied
+    /// into a buffer on the stack that is also wiped, instead of into a `Vec`.
+    ///
+    /// This can't wipe copies of the input made before it was passed in, such as when a `String`
+    /// grows and reallocates.
+    ///
+    /// ## Examples
+    /// ```
+    /// use canada_sin::{SINParseError, SIN};
+    /// let sin = SIN::parse_consuming("046-454-286".to_string()).unwrap();
+    /// assert_eq!(sin.digits_string(), "046454286");
+    /// assert_eq!(
+    ///     SIN::parse_consuming("046-454-287".to_string()),
+    ///     Err(SINParseError::InvalidChecksum)
+    /// );
+    /// ```
+    pub fn parse_consuming(mut s: String) -> Result<Self, SINParseError> {
+        let mut digits = Zeroizing::new([0; 9]);
+        let mut len = 0;
+        for khar in s.chars() {
+            if let Some(digit) = khar.to_digit(10) {
+                if let Some(slot) = digits.get_mut(len) {
+                    *slot = digit as u8;
+                }
+                len += 1;
+            }
+        }
+        s.zeroize();
+        match len {
+            n if n < 9 => Err(SINParseError::TooShort),
+            n if n > 9 => Err(SINParseError::TooLong),
+            _ if !luhn::is_valid(&*digits) => Err(SINParseError::InvalidChecksum),
+            _ => Ok(Self::from_valid_digits(*digits)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_to_owned)]
+    fn matches_parse() {
+        for input in [
+            "046 454 286",
+            "04645428",
+            "0464542860",
+            "SIN: 130-692-544",
+            "",
+        ] {
+            assert_eq!(
+                SIN::parse_consuming(input.to_string()),
+                SIN::parse(input.to_string())
+            );
+        }
+    }
+}
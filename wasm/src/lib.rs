@@ -0,0 +1,156 @@
+//! JavaScript bindings for `canada_sin`. Build an npm package with ES modules using
+//! `wasm-pack build wasm --target web` (or `--target bundler` for bundlers like webpack).
+//!
+//! The API follows JavaScript conventions: methods are camelCase, and invalid SINs throw a
+//! `SINParseError`, which has a `code` like `"INVALID_CHECKSUM"` that can be checked instead of
+//! matching on the message.
+//!
+//! ```js
+//! import init, { SIN, SINParseError } from "canada_sin_wasm";
+//!
+//! await init();
+//! const sin = new SIN("046-454-286");
+//! sin.digits; // "046454286"
+//! sin.masked; // "***-***-286"
+//! try {
+//!     SIN.parse("046-454-287");
+//! } catch (err) {
+//!     if (err instanceof SINParseError && err.code === "INVALID_CHECKSUM") {
+//!         // ...
+//!     }
+//! }
+//! ```
+
+use canada_sin::{Locale, SINParseError, SIN};
+use wasm_bindgen::prelude::*;
+
+/// The error thrown when a SIN can't be parsed.
+#[wasm_bindgen(js_name = SINParseError)]
+pub struct JsSINParseError {
+    error: SINParseError,
+}
+
+#[wasm_bindgen(js_class = SINParseError)]
+impl JsSINParseError {
+    /// Always `"SINParseError"`, like the `name` of built-in errors.
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        "SINParseError".to_string()
+    }
+    /// A code for the error, such as `"TOO_SHORT"` or `"INVALID_CHECKSUM"`. This is
+    /// `SINParseError::code` in upper case, in the style of JavaScript error codes.
+    #[wasm_bindgen(getter)]
+    pub fn code(&self) -> String {
+        self.error.code().to_ascii_uppercase()
+    }
+    /// A description of the error in English.
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.error.to_string()
+    }
+    /// A description of the error in French.
+    #[wasm_bindgen(getter, js_name = messageFr)]
+    pub fn message_fr(&self) -> String {
+        self.error.message(Locale::French).to_string()
+    }
+    /// Formats the error like built-in errors, as `SINParseError: <message>`.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        format!("SINParseError: {}", self.error)
+    }
+}
+
+impl From<SINParseError> for JsSINParseError {
+    fn from(error: SINParseError) -> Self {
+        Self { error }
+    }
+}
+
+/// A social insurance number.
+#[wasm_bindgen(js_name = SIN)]
+pub struct JsSIN {
+    sin: SIN,
+}
+
+#[wasm_bindgen(js_class = SIN)]
+impl JsSIN {
+    /// Parses a SIN, throwing a `SINParseError` if it isn't valid.
+    #[wasm_bindgen(constructor)]
+    pub fn new(s: &str) -> Result<JsSIN, JsSINParseError> {
+        Ok(Self {
+            sin: SIN::parse(s)?,
+        })
+    }
+    /// Parses a SIN, throwing a `SINParseError` if it isn't valid. This is the same as the
+    /// constructor.
+    pub fn parse(s: &str) -> Result<JsSIN, JsSINParseError> {
+        Self::new(s)
+    }
+    /// Checks if a string is a valid SIN, without throwing.
+    #[wasm_bindgen(js_name = isValid)]
+    pub fn is_valid(s: &str) -> bool {
+        SIN::is_valid(s)
+    }
+    /// The SIN as 9 digits, like `"046454286"`.
+    #[wasm_bindgen(getter)]
+    pub fn digits(&self) -> String {
+        self.sin.digits_string()
+    }
+    /// The SIN with dashes, like `"046-454-286"`.
+    #[wasm_bindgen(getter)]
+    pub fn dashed(&self) -> String {
+        self.sin.digits_dashed_string()
+    }
+    /// The SIN with all but the last 3 digits hidden, like `"***-***-286"`.
+    #[wasm_bindgen(getter)]
+    pub fn masked(&self) -> String {
+        self.sin.masked()
+    }
+    /// The English names of all types the SIN could be.
+    #[wasm_bindgen(getter)]
+    pub fn types(&self) -> Vec<String> {
+        self.sin.types().iter().map(|ty| ty.to_string()).collect()
+    }
+    /// Was the SIN given to a temporary resident?
+    #[wasm_bindgen(getter, js_name = isTemporary)]
+    pub fn is_temporary(&self) -> bool {
+        self.sin.is_nine_series()
+    }
+    /// Is the SIN a placeholder value that is never issued?
+    #[wasm_bindgen(getter, js_name = isPlaceholder)]
+    pub fn is_placeholder(&self) -> bool {
+        self.sin.is_placeholder()
+    }
+    /// Explains how the SIN is classified, in English.
+    pub fn explain(&self) -> String {
+        self.sin.explain()
+    }
+    /// Formats the SIN with dashes.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.sin.to_string()
+    }
+    /// Serializes the SIN as 9 digits for `JSON.stringify`.
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn to_json(&self) -> String {
+        self.digits()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors() {
+        let err = JsSIN::parse("046-454-287").err().unwrap();
+        assert_eq!(err.code(), "INVALID_CHECKSUM");
+        assert_eq!(
+            err.to_js_string(),
+            "SINParseError: SIN has an invalid checksum"
+        );
+        let sin = JsSIN::new("046 454 286").ok().unwrap();
+        assert_eq!(sin.masked(), "***-***-286");
+        assert_eq!(sin.to_json(), "046454286");
+    }
+}